@@ -0,0 +1,606 @@
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::ops::{Add, Sub, Mul, Neg};
+
+///An arbitrary precision integer, stored as a sign and the magnitude's base 2^32
+///digits, least significant first. The magnitude never has zeros on top, and zero
+///itself is the empty magnitude, which is never negative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BigInt {
+
+	negative: bool,
+	magnitude: Vec<u32>
+
+}
+
+///The largest power of ten in a digit, which decimal strings are handled in chunks of.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+
+///Drops the zero digits from the top of a magnitude.
+fn trim(mut magnitude: Vec<u32>) -> Vec<u32> {
+
+	while magnitude.last() == Some(&0) {
+
+		magnitude.pop();
+
+	}
+
+	return magnitude;
+
+}
+
+fn compareMagnitude(a: &[u32], b: &[u32]) -> Ordering {
+
+	if a.len() != b.len() {
+
+		return a.len().cmp(&b.len());
+
+	}
+
+	//the same length, so the highest digit that differs decides it
+	for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+
+		if x != y {
+
+			return x.cmp(y);
+
+		}
+
+	}
+
+	return Ordering::Equal;
+
+}
+
+fn addMagnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+
+	let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+	let mut carry: u64 = 0;
+
+	for i in 0..a.len().max(b.len()) {
+
+		let digit = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+		sum.push(digit as u32);
+		carry = digit >> 32;
+
+	}
+
+	sum.push(carry as u32);
+
+	return trim(sum);
+
+}
+
+///Subtracts b from a, which must be at least as large.
+fn subMagnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+
+	let mut difference = Vec::with_capacity(a.len());
+	let mut borrow: i64 = 0;
+
+	for (i, &digit) in a.iter().enumerate() {
+
+		let value = digit as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+		difference.push(value as u32);
+		borrow = if value < 0 { 1 } else { 0 };
+
+	}
+
+	return trim(difference);
+
+}
+
+fn mulMagnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+
+	if a.is_empty() || b.is_empty() {
+
+		return Vec::new();
+
+	}
+
+	let mut product = vec![0u32; a.len() + b.len()];
+
+	for (i, &x) in a.iter().enumerate() {
+
+		let mut carry: u64 = 0;
+
+		for (j, &y) in b.iter().enumerate() {
+
+			let digit = x as u64 * y as u64 + product[i + j] as u64 + carry;
+			product[i + j] = digit as u32;
+			carry = digit >> 32;
+
+		}
+
+		product[i + b.len()] = carry as u32;
+
+	}
+
+	return trim(product);
+
+}
+
+///Multiplies by a single digit and adds another, which builds numbers up from text.
+fn mulAddSmall(a: &[u32], factor: u32, addend: u32) -> Vec<u32> {
+
+	let mut result = Vec::with_capacity(a.len() + 1);
+	let mut carry = addend as u64;
+
+	for &digit in a {
+
+		let value = digit as u64 * factor as u64 + carry;
+		result.push(value as u32);
+		carry = value >> 32;
+
+	}
+
+	result.push(carry as u32);
+
+	return trim(result);
+
+}
+
+///Divides by a single digit. Returns the quotient and the remainder.
+fn divRemSmall(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+
+	let mut quotient = vec![0u32; a.len()];
+	let mut remainder: u64 = 0;
+
+	for i in (0..a.len()).rev() {
+
+		let value = (remainder << 32) | a[i] as u64;
+		quotient[i] = (value / divisor as u64) as u32;
+		remainder = value % divisor as u64;
+
+	}
+
+	return (trim(quotient), remainder as u32);
+
+}
+
+///Shifts left by fewer than 32 bits. The result always has one more digit, which
+///may be zero.
+fn shiftLeft(a: &[u32], shift: u32) -> Vec<u32> {
+
+	let mut result = Vec::with_capacity(a.len() + 1);
+	let mut carry = 0;
+
+	for &digit in a {
+
+		result.push((digit << shift) | carry);
+		carry = if shift == 0 { 0 } else { digit >> (32 - shift) };
+
+	}
+
+	result.push(carry);
+
+	return result;
+
+}
+
+///Shifts right by fewer than 32 bits.
+fn shiftRight(a: &[u32], shift: u32) -> Vec<u32> {
+
+	let mut result = vec![0u32; a.len()];
+
+	for i in 0..a.len() {
+
+		let above = if shift == 0 || i + 1 == a.len() { 0 } else { a[i + 1] << (32 - shift) };
+		result[i] = (a[i] >> shift) | above;
+
+	}
+
+	return trim(result);
+
+}
+
+///Divides a by b, which must not be zero, with Knuth's algorithm D. Each digit of the
+///quotient is guessed from the top two digits of what is left over and the top digit of
+///b, after shifting both so b's top bit is set, which makes the guess at most two too
+///big. Returns the quotient and the remainder.
+fn divRemMagnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+
+	if compareMagnitude(a, b) == Ordering::Less {
+
+		return (Vec::new(), a.to_vec());
+
+	}
+
+	if b.len() == 1 {
+
+		let (quotient, remainder) = divRemSmall(a, b[0]);
+
+		return (quotient, trim(vec![remainder]));
+
+	}
+
+	let shift = b[b.len() - 1].leading_zeros();
+	let mut divisor = shiftLeft(b, shift);
+	divisor.pop();
+
+	let mut rest = shiftLeft(a, shift);
+
+	let n = divisor.len();
+	let m = rest.len() - n - 1;
+	let mut quotient = vec![0u32; m + 1];
+
+	let top = divisor[n - 1] as u64;
+	let second = divisor[n - 2] as u64;
+
+	for j in (0..m + 1).rev() {
+
+		let leading = ((rest[j + n] as u64) << 32) | rest[j + n - 1] as u64;
+		let mut guess = leading / top;
+		let mut guessRemainder = leading % top;
+
+		//fix the guess using the next digit down, which leaves it at most one too big
+		while guess >> 32 != 0 || guess * second > ((guessRemainder << 32) | rest[j + n - 2] as u64) {
+
+			guess -= 1;
+			guessRemainder += top;
+
+			if guessRemainder >> 32 != 0 {
+
+				break;
+
+			}
+
+		}
+
+		//take guess * divisor off of the digits it lines up with
+		let mut borrow: i64 = 0;
+		let mut carry: u64 = 0;
+
+		for i in 0..n {
+
+			let product = guess * divisor[i] as u64 + carry;
+			carry = product >> 32;
+
+			let value = rest[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+			rest[i + j] = value as u32;
+			borrow = if value < 0 { 1 } else { 0 };
+
+		}
+
+		let value = rest[j + n] as i64 - borrow - carry as i64;
+		rest[j + n] = value as u32;
+
+		//the guess was one too big after all, so add one divisor back
+		if value < 0 {
+
+			guess -= 1;
+			let mut carry: u64 = 0;
+
+			for i in 0..n {
+
+				let sum = rest[i + j] as u64 + divisor[i] as u64 + carry;
+				rest[i + j] = sum as u32;
+				carry = sum >> 32;
+
+			}
+
+			rest[j + n] = rest[j + n].wrapping_add(carry as u32);
+
+		}
+
+		quotient[j] = guess as u32;
+
+	}
+
+	return (trim(quotient), shiftRight(&rest[..n], shift));
+
+}
+
+impl BigInt {
+
+	///Builds a BigInt from a sign and a magnitude, keeping zero non-negative.
+	fn fromParts(negative: bool, magnitude: Vec<u32>) -> BigInt {
+
+		let magnitude = trim(magnitude);
+
+		return BigInt { negative: negative && !magnitude.is_empty(), magnitude };
+
+	}
+
+	pub fn zero() -> BigInt {
+
+		return BigInt::fromParts(false, Vec::new());
+
+	}
+
+	pub fn isZero(&self) -> bool {
+
+		return self.magnitude.is_empty();
+
+	}
+
+	pub fn isNegative(&self) -> bool {
+
+		return self.negative;
+
+	}
+
+	///Divides, rounding the quotient toward zero, so the remainder has the sign of self.
+	///Returns the quotient and the remainder. Dividing by zero panics, the same as it
+	///does for the built in integers.
+	pub fn divRem(&self, other: &BigInt) -> (BigInt, BigInt) {
+
+		assert!(!other.isZero(), "Attempted to divide a BigInt by zero.");
+
+		let (quotient, remainder) = divRemMagnitude(&self.magnitude, &other.magnitude);
+
+		return (BigInt::fromParts(self.negative != other.negative, quotient), BigInt::fromParts(self.negative, remainder));
+
+	}
+
+	///Calculates the non-negative greatest common divisor with Euclid's algorithm.
+	pub fn gcd(&self, other: &BigInt) -> BigInt {
+
+		let mut a = self.magnitude.clone();
+		let mut b = other.magnitude.clone();
+
+		while !b.is_empty() {
+
+			let (_, remainder) = divRemMagnitude(&a, &b);
+
+			a = b;
+			b = remainder;
+
+		}
+
+		return BigInt::fromParts(false, a);
+
+	}
+
+}
+
+impl From<i64> for BigInt {
+
+	fn from(value: i64) -> BigInt {
+
+		let magnitude = value.unsigned_abs();
+
+		return BigInt::fromParts(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32]);
+
+	}
+
+}
+
+impl FromStr for BigInt {
+
+	type Err = String;
+
+	///Reads an optional minus sign followed by decimal digits.
+	fn from_str(text: &str) -> Result<BigInt, String> {
+
+		let (negative, digits) = if let Some(rest) = text.strip_prefix('-') { (true, rest) } else { (false, text) };
+
+		if digits.is_empty() || !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+
+			return Err(format!("'{}' is not an integer.", text));
+
+		}
+
+		let mut magnitude = Vec::new();
+
+		for digit in digits.bytes() {
+
+			magnitude = mulAddSmall(&magnitude, 10, (digit - b'0') as u32);
+
+		}
+
+		return Ok(BigInt::fromParts(negative, magnitude));
+
+	}
+
+}
+
+impl fmt::Display for BigInt {
+
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+		if self.isZero() {
+
+			return write!(f, "0");
+
+		}
+
+		//peel off nine decimal digits at a time, lowest first
+		let mut chunks = Vec::new();
+		let mut rest = self.magnitude.clone();
+
+		while !rest.is_empty() {
+
+			let (quotient, chunk) = divRemSmall(&rest, DECIMAL_CHUNK);
+			chunks.push(chunk);
+			rest = quotient;
+
+		}
+
+		let mut text = if self.negative { "-".to_string() } else { String::new() };
+		text.push_str(&chunks[chunks.len() - 1].to_string());
+
+		for chunk in chunks.iter().rev().skip(1) {
+
+			text.push_str(&format!("{:09}", chunk));
+
+		}
+
+		return write!(f, "{}", text);
+
+	}
+
+}
+
+impl Add<&BigInt> for &BigInt {
+
+	type Output = BigInt;
+
+	fn add(self, other: &BigInt) -> BigInt {
+
+		if self.negative == other.negative {
+
+			return BigInt::fromParts(self.negative, addMagnitude(&self.magnitude, &other.magnitude));
+
+		}
+
+		//opposite signs, so the smaller magnitude comes off the larger one
+		if compareMagnitude(&self.magnitude, &other.magnitude) == Ordering::Less {
+
+			return BigInt::fromParts(other.negative, subMagnitude(&other.magnitude, &self.magnitude));
+
+		} else {
+
+			return BigInt::fromParts(self.negative, subMagnitude(&self.magnitude, &other.magnitude));
+
+		}
+
+	}
+
+}
+
+impl Sub<&BigInt> for &BigInt {
+
+	type Output = BigInt;
+
+	fn sub(self, other: &BigInt) -> BigInt {
+
+		return self + &(-other);
+
+	}
+
+}
+
+impl Mul<&BigInt> for &BigInt {
+
+	type Output = BigInt;
+
+	fn mul(self, other: &BigInt) -> BigInt {
+
+		return BigInt::fromParts(self.negative != other.negative, mulMagnitude(&self.magnitude, &other.magnitude));
+
+	}
+
+}
+
+impl Neg for &BigInt {
+
+	type Output = BigInt;
+
+	fn neg(self) -> BigInt {
+
+		return BigInt::fromParts(!self.negative, self.magnitude.clone());
+
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	fn big(text: &str) -> BigInt {
+
+		return text.parse().unwrap();
+
+	}
+
+	#[test]
+	fn test_bigint_small_arithmetic(){
+
+		//everything that fits in an i64 has to agree with it
+		let values: Vec<i64> = vec![0, 1, -1, 7, -13, 4294967295, 4294967296, -4294967297, 123456789012, -987654321098, i32::MAX as i64 * 3];
+
+		for &a in values.iter() {
+
+			for &b in values.iter() {
+
+				let (x, y) = (BigInt::from(a), BigInt::from(b));
+
+				assert_eq!(&x + &y, BigInt::from(a + b));
+				assert_eq!(&x - &y, BigInt::from(a - b));
+
+				if (a as i128 * b as i128).abs() < i64::MAX as i128 {
+
+					assert_eq!(&x * &y, BigInt::from(a * b));
+
+				}
+
+				if b != 0 {
+
+					assert_eq!(x.divRem(&y), (BigInt::from(a / b), BigInt::from(a % b)));
+
+				}
+
+			}
+
+		}
+
+	}
+
+	#[test]
+	fn test_bigint_large_arithmetic(){
+
+		let a = big("340282366920938463463374607431768211457");
+		let b = big("-18446744073709551629");
+
+		assert_eq!((&a * &b).to_string(), "-6277101735386680768259460193179866441144672085150730813453");
+		assert_eq!((&a + &b).to_string(), "340282366920938463444927863358058659828");
+
+		//the quotient and remainder put back together give the dividend, with the
+		//remainder smaller than the divisor
+		let dividend = &(&a * &a) + &big("12345678901234567890123");
+		let (quotient, remainder) = dividend.divRem(&a);
+
+		assert_eq!(quotient, a);
+		assert_eq!(remainder, big("12345678901234567890123"));
+
+		let (quotient, remainder) = a.divRem(&b);
+		assert_eq!(&(&quotient * &b) + &remainder, a);
+		assert!(!remainder.isNegative());
+
+		//divisions with digits near the top and bottom of their range, which is where
+		//a wrong guess at a quotient digit shows up
+		let mut seed: u64 = 12345;
+		let mut digits = |count: usize| -> Vec<u32> {
+
+			return (0..count).map(|_| {
+
+				seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+				return match seed >> 62 { 0 => 0, 1 => 0xffff_ffff, _ => (seed >> 20) as u32 };
+
+			}).collect();
+
+		};
+
+		for round in 0..300 {
+
+			let dividend = BigInt::fromParts(false, digits(2 + round % 17));
+			let divisor = BigInt::fromParts(true, digits(2 + round % 5));
+
+			if divisor.isZero() {
+
+				continue;
+
+			}
+
+			let (quotient, remainder) = dividend.divRem(&divisor);
+
+			assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+			assert_eq!(compareMagnitude(&remainder.magnitude, &divisor.magnitude), Ordering::Less);
+
+		}
+
+		//a and b have nothing in common, but their multiples of g do
+		let g = big("1000000007");
+		assert_eq!((&a * &g).gcd(&(&b * &g)), g);
+
+		assert_eq!(big("-000123").to_string(), "-123");
+		assert_eq!(big("-0"), BigInt::zero());
+		assert!("12a".parse::<BigInt>().is_err());
+
+	}
+
+}
@@ -0,0 +1,375 @@
+
+use std::str;
+use std::fs::File;
+use std::io::Read;
+
+use nom::IResult::*;
+use nom::Err::*;
+use num_complex::Complex64;
+
+use poly::{Polynomial, Poly, Evaluate, integer, fp, get_min_err};
+use scalar::RootOfUnity;
+
+///A bivariate polynomial is a matrix of its coefficients. coeffs[i][j] is the
+///x^i y^j term, so each row is a polynomial in y, and every row has the same length.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BivariatePolynomial {
+
+	coeffs: Vec<Vec<Complex64>>
+
+}
+
+//Create a function named "coefficient" that looks for zero or more spaces or tabs,
+//followed by a complex number written the same way as in "polyfile".
+named!(coefficient<Complex64>, chain!(
+    many0!(alt!(tag!(" ") | tag!("\t"))) ~
+    re: fp ~
+    tag!(",") ~
+    many0!(alt!(tag!(" ") | tag!("\t"))) ~
+    im: fp ,
+    || Complex64::new(re, im)
+));
+
+//Create a function named "row" that looks for zero or more newlines followed by one
+//or more coefficients on the same line, and any spaces or tabs after them.
+named!(row<Vec<Complex64> >, chain!(
+    many0!(tag!("\n")) ~
+    coeffs: many1!(complete!(coefficient)) ~
+    many0!(alt!(tag!(" ") | tag!("\t"))) ,
+    || coeffs
+));
+
+//Create a function named "bivariatefile" that returns the row count, the column count
+//and the rows. It searches for the two counts separated by spaces or tabs, followed by
+//at least one newline, then zero or more rows.
+named!(bivariatefile <(i64, i64, Vec<Vec<Complex64> >)>,
+    chain!(
+        rows: integer ~
+        many1!(alt!(tag!(" ") | tag!("\t"))) ~
+        cols: integer ~
+        many1!(tag!("\n")) ~
+        coeffs: many0!(complete!(row)) ,
+        || {
+            return (rows, cols, coeffs)
+        })
+);
+
+impl BivariatePolynomial {
+
+	///Builds the polynomial from its rows of coefficients.
+	///Returns an error message if the rows are not all the same length.
+	pub fn new(coeffs: Vec<Vec<Complex64>>) -> Result<BivariatePolynomial, String> {
+
+		if let Some(first) = coeffs.first() {
+
+			for (i, row) in coeffs.iter().enumerate() {
+
+				if row.len() != first.len() {
+
+					return Err(format!("Row {} has {} coefficients instead of {}.", i + 1, row.len(), first.len()));
+
+				}
+
+			}
+
+		}
+
+		return Ok(BivariatePolynomial { coeffs });
+
+	}
+
+	///Reads the polynomial from a file with a "rows cols" header, followed by one row
+	///of "re,im" coefficients per line, separated by spaces or tabs.
+	///Returns the read polynomial on success, or an error message on failure.
+	pub fn readFromFile(filename: &str) -> Result<BivariatePolynomial, String> {
+
+		let file = File::open(filename.trim());
+		if file.is_err() { return Err("Failed to open file.".to_string()); }
+
+		let mut all_text = String::new();
+		let _ = file.unwrap().read_to_string(&mut all_text);
+
+		let (rows, cols, coeffs) = match bivariatefile(all_text.as_bytes()) {
+
+			Done(extra, result) => {
+
+				//there should be nothing left in the file, besides the newlines
+				//the parser stopped in front of
+				let extra_str = str::from_utf8(extra).unwrap().trim();
+
+				if extra_str.len() > 0 {
+
+					return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(extra_str.as_bytes())));
+
+				}
+
+				result
+
+			},
+			Error(Position(_, pos)) => return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(pos))),
+			_ => return Err("Parsing failed, invalid format.".to_string())
+
+		};
+
+		if rows != coeffs.len() as i64 {
+
+			return Err(format!("Incorrect number of rows, {} instead of {}.", coeffs.len(), rows));
+
+		}
+
+		for (i, row) in coeffs.iter().enumerate() {
+
+			if cols != row.len() as i64 {
+
+				return Err(format!("Row {} has {} coefficients instead of {}.", i + 1, row.len(), cols));
+
+			}
+
+		}
+
+		return BivariatePolynomial::new(coeffs);
+
+	}
+
+	///Generates a pretty looking string to print to the console.
+	pub fn print(&self) -> String {
+
+		let mut terms = Vec::new();
+
+		for (i, row) in self.coeffs.iter().enumerate() {
+
+			for (j, coeff) in row.iter().enumerate() {
+
+				terms.push(format!("({})(x^{} y^{})", coeff, i, j));
+
+			}
+
+		}
+
+		return terms.join(" + ");
+
+	}
+
+	///The number of rows, one more than the degree in x.
+	pub fn rows(&self) -> usize {
+
+		return self.coeffs.len();
+
+	}
+
+	///The number of columns, one more than the degree in y.
+	pub fn cols(&self) -> usize {
+
+		return self.coeffs.first().map_or(0, |row| row.len());
+
+	}
+
+	///Evaluates the polynomial at (x, y) by adding up each term's coefficient times
+	///its powers of x and y. Returns the value and the multiplication count.
+	pub fn evaluateAtNaive(&self, x: Complex64, y: Complex64) -> (Complex64, usize) {
+
+		let mut count: usize = 0;
+		let mut sum = Complex64::new(0.0, 0.0);
+
+		for (i, row) in self.coeffs.iter().enumerate() {
+
+			for (j, &coeff) in row.iter().enumerate() {
+
+				//the powers are multiplied out one at a time, the same as the count has
+				//them, instead of going through powf's polar form
+				let xPower = (0..i).fold(Complex64::new(1.0, 0.0), |power, _| power * x);
+				let yPower = (0..j).fold(Complex64::new(1.0, 0.0), |power, _| power * y);
+
+				sum = sum + coeff * xPower * yPower;
+				count += 2 + i + j;
+
+			}
+
+		}
+
+		return (sum, count);
+
+	}
+
+	///Evaluates the polynomial at (x, y) with nested Horner's method: each row is a
+	///polynomial in y, and their values are the coefficients of a polynomial in x.
+	///Returns the value and the multiplication count.
+	pub fn evaluateAtHorner(&self, x: Complex64, y: Complex64) -> (Complex64, usize) {
+
+		let mut count: usize = 0;
+		let mut inner = Vec::with_capacity(self.rows());
+
+		for row in self.coeffs.iter() {
+
+			let (value, rowCount) = Polynomial::from(row.clone()).evaluateAtHorner(y);
+			inner.push(value);
+			count += rowCount;
+
+		}
+
+		let (value, outerCount) = Polynomial::from(inner).evaluateAtHorner(x);
+
+		return (value, count + outerCount);
+
+	}
+
+	///Evaluates the polynomial on the grid of (w_n^j, w_m^k) pairs, where n is the
+	///number of rows and m the number of columns, with a 2D FFT. Each row goes through
+	///the 1D transform first, which leaves row i holding the x^i coefficient's values
+	///at every w_m^k, then each column of those goes through it again. values[j][k]
+	///is p(w_n^j, w_m^k). Any size works that evaluateAtFFT does.
+	///Returns the values and the multiplication count.
+	pub fn evaluateAtFFT(&self) -> (Vec<Vec<Complex64>>, usize) {
+
+		let mut count: usize = 0;
+		let mut partial = Vec::with_capacity(self.rows());
+
+		for row in self.coeffs.iter() {
+
+			let (values, rowCount) = Polynomial::from(row.clone()).evaluateAtFFT();
+			partial.push(values);
+			count += rowCount;
+
+		}
+
+		let mut values = vec![Vec::with_capacity(self.cols()); self.rows()];
+
+		for k in 0..self.cols() {
+
+			let column: Polynomial = partial.iter().map(|row| row[k]).collect();
+			let (columnValues, columnCount) = column.evaluateAtFFT();
+			count += columnCount;
+
+			for (j, value) in columnValues.into_iter().enumerate() {
+
+				values[j].push(value);
+
+			}
+
+		}
+
+		return (values, count);
+
+	}
+
+	///Evaluates at every point of the same grid evaluateAtFFT does, one point at a time
+	///with the given evaluator, for checking the FFT against.
+	///Returns the values and the multiplication count.
+	pub fn evaluateOnGrid<F>(&self, evaluator: F) -> (Vec<Vec<Complex64>>, usize)
+		where F: Fn(&BivariatePolynomial, Complex64, Complex64) -> (Complex64, usize) {
+
+		let mut count: usize = 0;
+		let xs: Vec<Complex64> = (0..self.rows()).map(|j| Complex64::rootOfUnity(self.rows(), j)).collect();
+		let ys: Vec<Complex64> = (0..self.cols()).map(|k| Complex64::rootOfUnity(self.cols(), k)).collect();
+		let mut values = Vec::with_capacity(self.rows());
+
+		for &x in xs.iter() {
+
+			let mut row = Vec::with_capacity(self.cols());
+
+			for &y in ys.iter() {
+
+				let (value, pointCount) = evaluator(self, x, y);
+				row.push(value);
+				count += pointCount;
+
+			}
+
+			values.push(row);
+
+		}
+
+		return (values, count);
+
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	fn assert_grids_close(attempt: &[Vec<Complex64>], answer: &[Vec<Complex64>]) {
+
+		assert_eq!(attempt.len(), answer.len());
+
+		for (attemptRow, answerRow) in attempt.iter().zip(answer.iter()) {
+
+			assert_eq!(attemptRow.len(), answerRow.len());
+
+			for (a, b) in attemptRow.iter().zip(answerRow.iter()) {
+
+				assert!((a - b).norm() < 1e-9, "{} != {}", a, b);
+
+			}
+
+		}
+
+	}
+
+	#[test]
+	fn test_read_bivariate_file(){
+
+		let result = BivariatePolynomial::readFromFile("./data/bivariate_valid.txt").unwrap();
+
+		assert_eq!((result.rows(), result.cols()), (2, 3));
+		assert_eq!(result.print(), "(1+0i)(x^0 y^0) + (2+1i)(x^0 y^1) + (0+0i)(x^0 y^2) + (-1+0.5i)(x^1 y^0) + (3+0i)(x^1 y^1) + (0-2i)(x^1 y^2)");
+
+		let result = BivariatePolynomial::readFromFile("./data/bivariate_invalid_short_row.txt");
+		assert_eq!(result, Err("Row 2 has 2 coefficients instead of 3.".to_string()));
+
+		let result = BivariatePolynomial::readFromFile("./data/bivariate_invalid_has_junk.txt");
+		assert_eq!(result, Err("Parsing failed. Invalid syntax \'junk\' in file.".to_string()));
+
+	}
+
+	#[test]
+	fn test_evaluate_bivariate(){
+
+		let poly = BivariatePolynomial::readFromFile("./data/bivariate_valid.txt").unwrap();
+
+		//p(x, y) = 1 + (2 + i)y - (1 - i/2)x + 3xy - 2i xy^2
+		let (x, y) = (Complex64::new(0.5, -1.0), Complex64::new(2.0, 0.25));
+		let answer = 1.0 + Complex64::new(2.0, 1.0) * y + Complex64::new(-1.0, 0.5) * x + 3.0 * x * y + Complex64::new(0.0, -2.0) * x * y * y;
+
+		let (value, count) = poly.evaluateAtNaive(x, y);
+		assert!((value - answer).norm() < 1e-12);
+		assert_eq!(count, 21);
+
+		let (value, count) = poly.evaluateAtHorner(x, y);
+		assert!((value - answer).norm() < 1e-12);
+		assert_eq!(count, 8);
+
+		//power of two sides, mixed radix sides, and a prime side that goes through
+		//Bluestein, each with complex and with purely real coefficients
+		for &(rows, cols) in [(2, 3), (8, 16), (12, 5), (7, 1), (1, 9)].iter() {
+
+			for &real in [false, true].iter() {
+
+				let coeffs = (0..rows).map(|i| (0..cols).map(|j| {
+
+					let re = ((i * 7 + j * 3) % 11) as f64 - 5.0;
+					let im = if real { 0.0 } else { ((i * 5 + j * 2) % 7) as f64 - 3.0 };
+
+					return Complex64::new(re, im);
+
+				}).collect()).collect();
+
+				let poly = BivariatePolynomial::new(coeffs).unwrap();
+				let (fft, _) = poly.evaluateAtFFT();
+				let (horner, _) = poly.evaluateOnGrid(BivariatePolynomial::evaluateAtHorner);
+				let (naive, _) = poly.evaluateOnGrid(BivariatePolynomial::evaluateAtNaive);
+
+				assert_grids_close(&fft, &horner);
+				assert_grids_close(&naive, &horner);
+
+			}
+
+		}
+
+		assert_eq!(BivariatePolynomial::new(vec![vec![Complex64::new(1.0, 0.0)], vec![]]), Err("Row 2 has 0 coefficients instead of 1.".to_string()));
+
+	}
+
+}
@@ -0,0 +1,455 @@
+
+use std::f64::consts;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use num_complex::Complex64;
+
+use poly::{Polynomial, Evaluate, twoSum, twoProduct};
+use scalar::{Scalar, RootOfUnity};
+
+///A double-double is the unevaluated sum hi + lo of two doubles, where lo is below
+///half an ulp of hi. That gives about 106 bits of mantissa, twice what an f64 has.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DoubleDouble {
+
+	pub hi: f64,
+	pub lo: f64
+
+}
+
+///Pi to double-double precision.
+const PI: DoubleDouble = DoubleDouble { hi: consts::PI, lo: 1.2246467991473532e-16 };
+
+///Adds two doubles when |a| >= |b|, which takes fewer operations than twoSum.
+fn quickTwoSum(a: f64, b: f64) -> DoubleDouble {
+
+	let hi = a + b;
+
+	return DoubleDouble { hi, lo: b - (hi - a) };
+
+}
+
+impl DoubleDouble {
+
+	///Creates a double-double holding exactly the given double.
+	pub fn new(value: f64) -> DoubleDouble {
+
+		return DoubleDouble { hi: value, lo: 0.0 };
+
+	}
+
+	///Rounds to the nearest double.
+	pub fn toF64(self) -> f64 {
+
+		return self.hi + self.lo;
+
+	}
+
+	///Calculates the square root with one Newton step from the double's square root,
+	///which doubles the number of correct bits.
+	pub fn sqrt(self) -> DoubleDouble {
+
+		if self.hi <= 0.0 {
+
+			return DoubleDouble::new(0.0);
+
+		}
+
+		let guess = DoubleDouble::new(self.hi.sqrt());
+
+		return guess + (self - guess * guess) / (guess * DoubleDouble::new(2.0));
+
+	}
+
+	///Calculates the sine and cosine with their Taylor series, which is only accurate
+	///for small angles, so it is only used for angles within pi/4 of zero.
+	fn sinCosSmall(self) -> (DoubleDouble, DoubleDouble) {
+
+		let square = self * self;
+		let mut sin = self;
+		let mut cos = DoubleDouble::new(1.0);
+		let mut sinTerm = self;
+		let mut cosTerm = DoubleDouble::new(1.0);
+
+		//(pi/4)^30 / 30! is far below the last bit, so 15 terms of each is plenty
+		for j in 1..16 {
+
+			let j = j as f64;
+
+			sinTerm = -(sinTerm * square) / DoubleDouble::new((2.0 * j) * (2.0 * j + 1.0));
+			cosTerm = -(cosTerm * square) / DoubleDouble::new((2.0 * j - 1.0) * (2.0 * j));
+
+			sin = sin + sinTerm;
+			cos = cos + cosTerm;
+
+		}
+
+		return (sin, cos);
+
+	}
+
+}
+
+impl Add for DoubleDouble {
+
+	type Output = DoubleDouble;
+
+	fn add(self, other: DoubleDouble) -> DoubleDouble {
+
+		//add the high and low parts separately, so cancellation in the high parts
+		//doesn't lose the low ones
+		let (hi, hiError) = twoSum(self.hi, other.hi);
+		let (lo, loError) = twoSum(self.lo, other.lo);
+
+		let sum = quickTwoSum(hi, hiError + lo);
+
+		return quickTwoSum(sum.hi, sum.lo + loError);
+
+	}
+
+}
+
+impl Sub for DoubleDouble {
+
+	type Output = DoubleDouble;
+
+	fn sub(self, other: DoubleDouble) -> DoubleDouble {
+
+		return self + (-other);
+
+	}
+
+}
+
+impl Mul for DoubleDouble {
+
+	type Output = DoubleDouble;
+
+	fn mul(self, other: DoubleDouble) -> DoubleDouble {
+
+		let (product, error) = twoProduct(self.hi, other.hi);
+
+		return quickTwoSum(product, error + (self.hi * other.lo + self.lo * other.hi));
+
+	}
+
+}
+
+impl Div for DoubleDouble {
+
+	type Output = DoubleDouble;
+
+	///Long division, one double's worth of quotient at a time.
+	fn div(self, other: DoubleDouble) -> DoubleDouble {
+
+		let first = self.hi / other.hi;
+		let remainder = self - other * DoubleDouble::new(first);
+
+		let second = remainder.hi / other.hi;
+		let remainder = remainder - other * DoubleDouble::new(second);
+
+		let third = remainder.hi / other.hi;
+
+		return quickTwoSum(first, second) + DoubleDouble::new(third);
+
+	}
+
+}
+
+impl Neg for DoubleDouble {
+
+	type Output = DoubleDouble;
+
+	fn neg(self) -> DoubleDouble {
+
+		return DoubleDouble { hi: -self.hi, lo: -self.lo };
+
+	}
+
+}
+
+///A complex number with double-double real and imaginary parts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ComplexDD {
+
+	pub re: DoubleDouble,
+	pub im: DoubleDouble
+
+}
+
+impl ComplexDD {
+
+	pub fn new(re: DoubleDouble, im: DoubleDouble) -> ComplexDD {
+
+		return ComplexDD { re, im };
+
+	}
+
+	///Calculates the absolute value.
+	pub fn norm(self) -> DoubleDouble {
+
+		return (self.re * self.re + self.im * self.im).sqrt();
+
+	}
+
+}
+
+impl From<Complex64> for ComplexDD {
+
+	fn from(value: Complex64) -> ComplexDD {
+
+		return ComplexDD::new(DoubleDouble::new(value.re), DoubleDouble::new(value.im));
+
+	}
+
+}
+
+impl Add for ComplexDD {
+
+	type Output = ComplexDD;
+
+	fn add(self, other: ComplexDD) -> ComplexDD {
+
+		return ComplexDD::new(self.re + other.re, self.im + other.im);
+
+	}
+
+}
+
+impl Sub for ComplexDD {
+
+	type Output = ComplexDD;
+
+	fn sub(self, other: ComplexDD) -> ComplexDD {
+
+		return ComplexDD::new(self.re - other.re, self.im - other.im);
+
+	}
+
+}
+
+impl Mul for ComplexDD {
+
+	type Output = ComplexDD;
+
+	fn mul(self, other: ComplexDD) -> ComplexDD {
+
+		return ComplexDD::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re);
+
+	}
+
+}
+
+impl Neg for ComplexDD {
+
+	type Output = ComplexDD;
+
+	fn neg(self) -> ComplexDD {
+
+		return ComplexDD::new(-self.re, -self.im);
+
+	}
+
+}
+
+impl Scalar for ComplexDD {
+
+	fn zero() -> ComplexDD {
+
+		return ComplexDD::from(Complex64::new(0.0, 0.0));
+
+	}
+
+	fn one() -> ComplexDD {
+
+		return ComplexDD::from(Complex64::new(1.0, 0.0));
+
+	}
+
+}
+
+impl RootOfUnity for ComplexDD {
+
+	fn rootOfUnity(n: usize, k: usize) -> ComplexDD {
+
+		//2 pi k / n is split into whole eighths of a turn and what is left over, which
+		//is all done exactly on the integers. Then the Taylor series only ever sees an
+		//angle within pi/4 of zero.
+		let eighths = 8 * (k % n);
+		let octant = eighths / n;
+		let leftover = eighths % n;
+
+		let eighth = PI / DoubleDouble::new(4.0);
+
+		//odd octants are measured back from the next quarter turn instead
+		let (quarter, angle) = if octant % 2 == 0 {
+
+			(octant / 2, eighth * DoubleDouble::new(leftover as f64) / DoubleDouble::new(n as f64))
+
+		} else {
+
+			(octant / 2 + 1, -(eighth * DoubleDouble::new((n - leftover) as f64) / DoubleDouble::new(n as f64)))
+
+		};
+
+		let (sin, cos) = angle.sinCosSmall();
+
+		//turning by a quarter is exact
+		return match quarter % 4 {
+
+			0 => ComplexDD::new(cos, sin),
+			1 => ComplexDD::new(-sin, cos),
+			2 => ComplexDD::new(-cos, -sin),
+			_ => ComplexDD::new(sin, -cos)
+
+		};
+
+	}
+
+}
+
+///Evaluates the polynomial at the n roots of unity in double-double precision, to be
+///the reference the Complex64 algorithms are checked against. Power of two lengths go
+///through the FFT, and anything else through Horner's method at each root.
+pub fn evaluateReference(poly: &Polynomial) -> Vec<ComplexDD> {
+
+	let wide: Polynomial<ComplexDD> = poly.iter().map(|&coeff| ComplexDD::from(coeff)).collect();
+
+	if let Ok((values, _)) = wide.evaluateAtFFTRadix2() {
+
+		return values;
+
+	}
+
+	let n = wide.len();
+
+	return (0..n).map(|k| wide.evaluateAtHorner(ComplexDD::rootOfUnity(n, k)).0).collect();
+
+}
+
+///Evaluates the polynomial at each of the points in double-double precision with
+///Horner's method. The points are taken exactly as they are, so this is the reference
+///for algorithms given the same Complex64 points, rounding of the points aside.
+pub fn evaluateReferenceAt(poly: &Polynomial, points: &[Complex64]) -> Vec<ComplexDD> {
+
+	let wide: Polynomial<ComplexDD> = poly.iter().map(|&coeff| ComplexDD::from(coeff)).collect();
+
+	return points.iter().map(|&point| wide.evaluateAtHorner(ComplexDD::from(point)).0).collect();
+
+}
+
+///Finds the largest absolute and relative errors of the values against the reference.
+///Reference values of zero are left out of the relative error, but ones that are only
+///nearly zero can still make it huge.
+pub fn maxErrors(values: &[Complex64], reference: &[ComplexDD]) -> (f64, f64) {
+
+	let mut maxAbsolute: f64 = 0.0;
+	let mut maxRelative: f64 = 0.0;
+
+	for (&value, &exact) in values.iter().zip(reference.iter()) {
+
+		let error = (ComplexDD::from(value) - exact).norm().toF64();
+		let size = exact.norm().toF64();
+
+		maxAbsolute = maxAbsolute.max(error);
+
+		if size > 0.0 {
+
+			maxRelative = maxRelative.max(error / size);
+
+		}
+
+	}
+
+	return (maxAbsolute, maxRelative);
+
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use poly::{Poly, rootsOfUnity};
+
+	#[test]
+	fn test_double_double_arithmetic(){
+
+		//1 + 2^-80 doesn't fit in an f64, but does in a double-double
+		let tiny = DoubleDouble::new(2.0f64.powi(-80));
+		let sum = DoubleDouble::new(1.0) + tiny;
+
+		assert_eq!(sum.hi, 1.0);
+		assert_eq!(sum.lo, tiny.hi);
+		assert_eq!((sum - DoubleDouble::new(1.0)).toF64(), tiny.hi);
+
+		//a third times three is one to well past double precision
+		let third = DoubleDouble::new(1.0) / DoubleDouble::new(3.0);
+		let one = third * DoubleDouble::new(3.0);
+
+		assert!((one - DoubleDouble::new(1.0)).toF64().abs() < 1e-31);
+		assert!((DoubleDouble::new(2.0).sqrt() * DoubleDouble::new(2.0).sqrt() - DoubleDouble::new(2.0)).toF64().abs() < 1e-31);
+
+	}
+
+	#[test]
+	fn test_double_double_roots(){
+
+		for &n in [1, 3, 8, 12, 100, 1024].iter() {
+
+			for k in 0..n {
+
+				let root = ComplexDD::rootOfUnity(n, k);
+
+				//every root is on the unit circle, and the double rounding of it is
+				//as close as the Complex64 one
+				assert!((root.norm() - DoubleDouble::new(1.0)).toF64().abs() < 1e-30);
+				assert!((Complex64::new(root.re.toF64(), root.im.toF64()) - Complex64::rootOfUnity(n, k)).norm() < 1e-15);
+
+				//and it really is an n-th root
+				assert!((root.powi(n) - ComplexDD::one()).norm().toF64() < 1e-28);
+
+			}
+
+		}
+
+		//a twelfth of a turn is exactly (sqrt(3) / 2, 1 / 2)
+		let root = ComplexDD::rootOfUnity(12, 1);
+		assert!((root.im - DoubleDouble::new(0.5)).toF64().abs() < 1e-31);
+		assert!((root.re - DoubleDouble::new(3.0).sqrt() / DoubleDouble::new(2.0)).toF64().abs() < 1e-31);
+
+	}
+
+	#[test]
+	fn test_reference(){
+
+		for file in ["data/poly_0064.txt", "data/poly_1024.txt", "data/test_25.txt"].iter() {
+
+			let poly = Polynomial::readFromFile(&file.to_string()).unwrap();
+			let reference = evaluateReference(&poly);
+
+			//test_25.txt vanishes at all but one root, so the errors are measured against
+			//the size of the coefficients instead of the values
+			let scale: f64 = poly.iter().map(|coeff| coeff.norm()).sum();
+
+			//the FFT and Horner's method should both be good to near double precision
+			let (fftValues, _) = poly.evaluateAtFFT();
+			let (fftAbsolute, _) = maxErrors(&fftValues, &reference);
+
+			let hornerValues: Vec<Complex64> = (0..poly.len()).map(|k| poly.evaluateAtHorner(Complex64::rootOfUnity(poly.len(), k)).0).collect();
+			let (hornerAbsolute, _) = maxErrors(&hornerValues, &reference);
+
+			assert!(fftAbsolute / scale < 1e-14, "{} FFT error {:e}", file, fftAbsolute / scale);
+			assert!(hornerAbsolute / scale < 1e-13, "{} Horner error {:e}", file, hornerAbsolute / scale);
+
+			//compensated Horner's is about as good as double-double at the same points
+			let roots: Vec<Complex64> = rootsOfUnity(poly.len() as i32);
+			let compensatedValues: Vec<Complex64> = roots.iter().map(|&root| poly.evaluateAtHornerCompensated(root).0).collect();
+			let (compensatedAbsolute, _) = maxErrors(&compensatedValues, &evaluateReferenceAt(&poly, &roots));
+
+			assert!(compensatedAbsolute / scale < 1e-15, "{} compensated error {:e}", file, compensatedAbsolute / scale);
+
+		}
+
+	}
+
+}
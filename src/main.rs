@@ -6,16 +6,31 @@ extern crate num_complex;
 extern crate rand;
 
 mod poly;
+mod ntt;
+mod scalar;
+mod doubledouble;
+mod bigint;
+mod rational;
+mod sparse;
+mod bivariate;
 
 use std::io;
 use std::io::Write;
+use std::thread;
 use std::time::Instant;
 
-use poly::{Polynomial, Poly, rootsOfUnity};
+use num_complex::Complex64;
+use poly::{Polynomial, Poly, Evaluate, FftPlan, rootsOfUnity, printTerms};
+use ntt::{IntPolynomial, IntPoly};
+use rational::GaussianRational;
+use sparse::{SparsePolynomial, isSparse};
+use bivariate::BivariatePolynomial;
+use doubledouble::{evaluateReference, evaluateReferenceAt, maxErrors};
 
 fn main(){
 
 	let mut polynomial = Polynomial::new();
+	let mut values: Vec<Complex64> = Vec::new();
 
 	loop {
 
@@ -30,31 +45,87 @@ fn main(){
 		println!("5.  Evaluate the current polynomial using Horner's method.");
 		println!("6.  Evaluate the current polynomial using the improved naive method");
 		println!("7.  Evaluate the current polynomial using the Fast Fourier Transform.");
-		println!("8.  Display run times for the above four algorithms.");
-		println!("9.  Display the multiplpication count for the above four algorithms.");
-		println!("10. Quit the program.\n");
+		println!("8.  Display run times for the evaluation algorithms.");
+		println!("9.  Display the multiplpication count for the evaluation algorithms.");
+		println!("10. Interpolate the current values back into a polynomial.");
+		println!("11. Multiply the current polynomial by one read from a file.");
+		println!("12. Evaluate an integer polynomial read from a file using the NTT.");
+		println!("13. Multiply two integer polynomials read from files using the NTT.");
+		println!("14. Evaluate the current polynomial at chosen roots of unity using Goertzel's algorithm.");
+		println!("15. Evaluate the current polynomial at points read from a file using a subproduct tree.");
+		println!("16. Compare Horner's method with compensated Horner's at each root of unity.");
+		println!("17. Display the rounding error bounds for the evaluation algorithms.");
+		println!("18. Evaluate the current polynomial and its derivatives at a point.");
+		println!("19. Find the roots of the current polynomial using the Aberth-Ehrlich method.");
+		println!("20. Divide the current polynomial by one read from a file.");
+		println!("21. Replace the current polynomial with its GCD with one read from a file.");
+		println!("22. Deflate the current polynomial by dividing out (x - a) for a known root a.");
+		println!("23. Compose the current polynomial with one read from a file, p(q(x)).");
+		println!("24. Replace the current polynomial with its derivative.");
+		println!("25. Replace the current polynomial with its antiderivative.");
+		println!("26. Replace the current polynomial p(x) with its Taylor shift p(x + a).");
+		println!("27. Display each algorithm's error against a double-double reference.");
+		println!("28. Evaluate a rational polynomial read from a file exactly at a rational point.");
+		println!("29. Evaluate a sparse polynomial read from a file at a point.");
+		println!("30. Read a sparse polynomial from a file as the current polynomial.");
+		println!("31. Write the current polynomial to a file in the sparse format.");
+		println!("32. Evaluate a bivariate polynomial read from a file on its grid of roots of unity.");
+		println!("33. Quit the program.\n");
 
 		println!("The current polynomial is: {}", polynomial.print());
 		print!("Your choice: ");
 		let _ = io::stdout().flush();
 		io::stdin().read_line(&mut choice).expect("stdin is broken.");
 
+		let previous = polynomial.clone();
+
 		match choice.trim() {
 
 			"1" 	=> polynomial = generateRandomPolynomial(),
 			"2" 	=> polynomial = readFromFile(),
 			"3" 	=> writeToFile(&polynomial),
-			"4" 	=> evaluateNaive(&polynomial),
-			"5" 	=> evaluateHorner(&polynomial),
-			"6" 	=> evaluateNaiveImproved(&polynomial),
-			"7" 	=> evaluateFFT(&polynomial),
+			"4" 	=> values = evaluateNaive(&polynomial),
+			"5" 	=> values = evaluateHorner(&polynomial),
+			"6" 	=> values = evaluateNaiveImproved(&polynomial),
+			"7" 	=> values = evaluateFFT(&polynomial),
 			"8" 	=> benchmarkAlgorithms(&polynomial),
 			"9" 	=> displayMultiplyCount(&polynomial),
-			"10"	=> return,
+			"10"	=> polynomial = interpolate(&values, &polynomial),
+			"11"	=> polynomial = multiply(&polynomial),
+			"12"	=> evaluateNTT(),
+			"13"	=> multiplyNTT(),
+			"14"	=> evaluateGoertzel(&polynomial),
+			"15"	=> evaluateAtPoints(&polynomial),
+			"16"	=> compareHornerCompensated(&polynomial),
+			"17"	=> displayErrorBounds(&polynomial),
+			"18"	=> evaluateDerivatives(&polynomial),
+			"19"	=> findRoots(&polynomial),
+			"20"	=> polynomial = divide(&polynomial),
+			"21"	=> polynomial = gcd(&polynomial),
+			"22"	=> polynomial = deflate(&polynomial),
+			"23"	=> polynomial = compose(&polynomial),
+			"24"	=> polynomial = differentiate(&polynomial),
+			"25"	=> polynomial = integrate(&polynomial),
+			"26"	=> polynomial = taylorShift(&polynomial),
+			"27"	=> displayReferenceErrors(&polynomial),
+			"28"	=> evaluateRational(),
+			"29"	=> evaluateSparse(),
+			"30"	=> polynomial = readSparseFromFile().toDense(),
+			"31"	=> writeSparseToFile(&polynomial),
+			"32"	=> evaluateBivariate(),
+			"33"	=> return,
 			_   	=> println!("Invalid choice. Please try again.")
 
 		}
 
+		//values from evaluating an older polynomial would interpolate back into it
+		//instead of the current one, so they go as soon as the polynomial changes
+		if polynomial != previous {
+
+			values.clear();
+
+		}
+
 	}
 
 }
@@ -149,7 +220,7 @@ fn writeToFile(poly: &Polynomial){
 
 }
 
-fn evaluateNaive(poly: &Polynomial){
+fn evaluateNaive(poly: &Polynomial) -> Vec<Complex64> {
 
 	//generate the n roots of unity for the polynomial
 	println!("Generating roots of unity...");
@@ -165,11 +236,13 @@ fn evaluateNaive(poly: &Polynomial){
 
 	}
 
-	println!("Done. The values are: {}", values.print());
+	println!("Done. The values are: {}", printTerms(&values));
+
+	return values;
 
 }
 
-fn evaluateHorner(poly: &Polynomial){
+fn evaluateHorner(poly: &Polynomial) -> Vec<Complex64> {
 
 	//generate the n roots of unity for the polynomial
 	println!("Generating roots of unity...");
@@ -185,11 +258,322 @@ fn evaluateHorner(poly: &Polynomial){
 
 	}
 
-	println!("Done. The values are: {}", values.print());
+	println!("Done. The values are: {}", printTerms(&values));
+
+	return values;
 
 }
 
-fn evaluateNaiveImproved(poly: &Polynomial){
+fn compareHornerCompensated(poly: &Polynomial){
+
+	//generate the n roots of unity for the polynomial
+	println!("Generating roots of unity...");
+	let roots = rootsOfUnity(poly.len() as i32);
+
+	println!("Evaluating polynomial using Horner's method and compensated Horner's...");
+
+	let mut results = Vec::new();
+
+	for root in roots {
+
+		let (plain, _) = poly.evaluateAtHorner(root);
+		let (compensated, _) = poly.evaluateAtHornerCompensated(root);
+		results.push((plain, compensated));
+
+	}
+
+	println!("Done. Results:");
+
+	for (k, (plain, compensated)) in results.into_iter().enumerate() {
+
+		println!("p(w^{}): Horner's {}, Compensated {}, Difference {:e}", k, plain, compensated, (plain - compensated).norm());
+
+	}
+
+}
+
+///Works out how many significant digits of the value the error bound leaves intact.
+fn trustedDigits(value: Complex64, bound: f64) -> i32 {
+
+	if bound == 0.0 {
+
+		return 16;
+
+	}
+
+	let digits = -(bound / value.norm()).log10().ceil();
+
+	return digits.clamp(0.0, 16.0) as i32;
+
+}
+
+fn displayErrorBounds(poly: &Polynomial){
+
+	//generate the n roots of unity for the polynomial
+	println!("Generating roots of unity...");
+	let roots = rootsOfUnity(poly.len() as i32);
+
+	println!("Evaluating polynomial with error bounds...");
+
+	let (fftValues, fftBounds, _) = poly.evaluateAtFFTWithBound();
+
+	println!("Done. Results:");
+
+	for (k, root) in roots.into_iter().enumerate() {
+
+		let (naive, naiveBound, _) 			= poly.evaluateAtNaiveWithBound(root);
+		let (horner, hornerBound, _) 		= poly.evaluateAtHornerWithBound(root);
+		let (improved, improvedBound, _) 	= poly.evaluateAtNaiveImprovedWithBound(root);
+
+		println!("p(w^{}):", k);
+		println!("Naive:          {} +/- {:e}, {} digits", naive, naiveBound, trustedDigits(naive, naiveBound));
+		println!("Horner's:       {} +/- {:e}, {} digits", horner, hornerBound, trustedDigits(horner, hornerBound));
+		println!("Naive Improved: {} +/- {:e}, {} digits", improved, improvedBound, trustedDigits(improved, improvedBound));
+		println!("FFT:            {} +/- {:e}, {} digits", fftValues[k], fftBounds[k], trustedDigits(fftValues[k], fftBounds[k]));
+
+	}
+
+}
+
+fn displayReferenceErrors(poly: &Polynomial){
+
+	//generate the n roots of unity for the polynomial
+	println!("Generating roots of unity...");
+	let roots = rootsOfUnity(poly.len() as i32);
+
+	println!("Evaluating polynomial in double-double precision...");
+
+	//the FFT is checked against the exact roots of unity, but the rest are given the
+	//rounded ones, so they are checked against the polynomial at those instead
+	let reference 			= evaluateReference(poly);
+	let roundedReference 	= evaluateReferenceAt(poly, &roots);
+
+	println!("Evaluating polynomial with each algorithm...");
+
+	let naive: Vec<Complex64> 		= roots.iter().map(|&root| poly.evaluateAtNaive(root).0).collect();
+	let horner: Vec<Complex64> 		= roots.iter().map(|&root| poly.evaluateAtHorner(root).0).collect();
+	let improved: Vec<Complex64> 	= roots.iter().map(|&root| poly.evaluateAtNaiveImproved(root).0).collect();
+	let estrin: Vec<Complex64> 		= roots.iter().map(|&root| poly.evaluateAtEstrin(root).0).collect();
+	let compensated: Vec<Complex64> = roots.iter().map(|&root| poly.evaluateAtHornerCompensated(root).0).collect();
+	let (fft, _) 					= poly.evaluateAtFFT();
+
+	println!("Done. Maximum absolute and relative errors:");
+
+	let rows = [("Naive:          ", naive, &roundedReference), ("Horner's:       ", horner, &roundedReference),
+		("Naive Improved: ", improved, &roundedReference), ("Estrin's:       ", estrin, &roundedReference),
+		("Compensated:    ", compensated, &roundedReference), ("FFT:            ", fft, &reference)];
+
+	for &(name, ref values, exact) in rows.iter() {
+
+		let (absolute, relative) = maxErrors(values, exact);
+		println!("{}{:e} absolute, {:e} relative", name, absolute, relative);
+
+	}
+
+}
+
+fn readPoint() -> Complex64 {
+
+	let mut input = String::new();
+
+	print!("Enter a point as real,imaginary: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut input).expect("stdin is broken.");
+
+	let parts: Vec<Result<f64, _>> = input.trim().split(',').map(|part| part.trim().parse()).collect();
+
+	if parts.len() != 2 || parts.iter().any(|part| part.is_err()) {
+
+		println!("Please enter two decimal numbers separated by a comma.");
+		return readPoint();
+
+	}
+
+	return Complex64::new(*parts[0].as_ref().unwrap(), *parts[1].as_ref().unwrap());
+
+}
+
+fn evaluateDerivatives(poly: &Polynomial){
+
+	let point = readPoint();
+	let mut input = String::new();
+
+	print!("Enter how many derivatives you'd like: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut input).expect("stdin is broken.");
+
+	let k = input.trim().parse();
+	if let Err(e) = k {
+
+		println!("Please enter an integer zero or above: {:?}", e);
+		return evaluateDerivatives(poly);
+
+	}
+
+	let k: usize = k.unwrap();
+
+	println!("Evaluating polynomial and its derivatives using Horner's method...");
+
+	let (derivatives, count) = poly.evaluateDerivativesAtHorner(point, k);
+
+	println!("Done. {} mulitplies. The values are:", count);
+
+	for (j, derivative) in derivatives.iter().enumerate() {
+
+		println!("p^({})({}) = {}", j, point, derivative);
+
+	}
+
+}
+
+fn findRoots(poly: &Polynomial){
+
+	let mut input = String::new();
+
+	print!("Enter the convergence tolerance: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut input).expect("stdin is broken.");
+
+	let tolerance = input.trim().parse();
+	if let Err(_) = tolerance {
+
+		println!("Please enter a decimal number above zero.");
+		return findRoots(poly);
+
+	}
+
+	let tolerance: f64 = tolerance.unwrap();
+
+	input.clear();
+	print!("Enter the maximum number of iterations: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut input).expect("stdin is broken.");
+
+	let maxIterations = input.trim().parse();
+	if let Err(e) = maxIterations {
+
+		println!("Please enter an integer above zero: {:?}", e);
+		return findRoots(poly);
+
+	}
+
+	let maxIterations: usize = maxIterations.unwrap();
+
+	println!("Finding roots using the Aberth-Ehrlich method...");
+
+	match poly.rootsAberth(tolerance, maxIterations) {
+
+		Ok((roots, residuals, iterations)) => {
+
+			println!("Done after {} iterations. The roots are:", iterations);
+
+			for (root, residual) in roots.iter().zip(residuals.iter()) {
+
+				println!("{} with |p(x)| = {:e}", root, residual);
+
+			}
+
+		},
+		Err(e) => println!("Could not find the roots: {}", e)
+
+	}
+
+}
+
+fn readTolerance() -> f64 {
+
+	let mut input = String::new();
+
+	print!("Enter the tolerance for treating a coefficient as zero: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut input).expect("stdin is broken.");
+
+	let tolerance = input.trim().parse();
+	if let Err(_) = tolerance {
+
+		println!("Please enter a decimal number zero or above.");
+		return readTolerance();
+
+	}
+
+	return tolerance.unwrap();
+
+}
+
+fn divide(poly: &Polynomial) -> Polynomial {
+
+	let divisor = readFromFile();
+	let tolerance = readTolerance();
+
+	println!("Dividing polynomials using long division...");
+
+	match poly.divide(&divisor, tolerance) {
+
+		Ok((quotient, remainder, count)) => {
+
+			println!("Done. {} mulitplies.", count);
+			println!("The quotient is: {}", quotient.print());
+			println!("The remainder is: {}", remainder.print());
+
+			return quotient;
+
+		},
+		Err(e) => {
+
+			println!("Could not divide: {}", e);
+
+			return poly.clone();
+
+		}
+
+	}
+
+}
+
+fn gcd(poly: &Polynomial) -> Polynomial {
+
+	let other = readFromFile();
+	let tolerance = readTolerance();
+
+	println!("Finding the greatest common divisor using Euclid's algorithm...");
+
+	match poly.gcd(&other, tolerance) {
+
+		Ok((divisor, count)) => {
+
+			println!("Done. {} mulitplies. The GCD is: {}", count, divisor.print());
+
+			return divisor;
+
+		},
+		Err(e) => {
+
+			println!("Could not find the GCD: {}", e);
+
+			return poly.clone();
+
+		}
+
+	}
+
+}
+
+fn deflate(poly: &Polynomial) -> Polynomial {
+
+	let root = readPoint();
+
+	println!("Dividing out (x - {}) using synthetic division...", root);
+
+	let (quotient, remainder, count) = poly.divideSynthetic(root);
+
+	println!("Done. {} mulitplies. The remainder is {}.", count, remainder);
+	println!("The quotient is: {}", quotient.print());
+
+	return quotient;
+
+}
+
+fn evaluateNaiveImproved(poly: &Polynomial) -> Vec<Complex64> {
 
 	//generate the n roots of unity for the polynomial
 	println!("Generating roots of unity...");
@@ -205,17 +589,432 @@ fn evaluateNaiveImproved(poly: &Polynomial){
 
 	}
 
-	println!("Done. The values are: {}", values.print());
+	println!("Done. The values are: {}", printTerms(&values));
+
+	return values;
 
 }
 
-fn evaluateFFT(poly: &Polynomial){
+fn evaluateFFT(poly: &Polynomial) -> Vec<Complex64> {
 
 	println!("Evaluating polynomial using the FFT...");
 
 	let (values, _) = poly.evaluateAtFFT();
 
-	println!("Done. The values are: {}", values.print());
+	println!("Done. The values are: {}", printTerms(&values));
+
+	return values;
+
+}
+
+fn evaluateGoertzel(poly: &Polynomial){
+
+	let mut input = String::new();
+
+	print!("Enter the indices k of the roots of unity, separated by spaces: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut input).expect("stdin is broken.");
+
+	let bins: Result<Vec<usize>, _> = input.split_whitespace().map(|k| k.parse()).collect();
+	if let Err(e) = bins {
+
+		println!("Please enter integers zero or above: {:?}", e);
+		return evaluateGoertzel(poly);
+
+	}
+
+	let bins = bins.unwrap();
+
+	println!("Evaluating polynomial using Goertzel's algorithm...");
+
+	let (values, count) = poly.evaluateAtGoertzel(&bins);
+
+	println!("Done. {} mulitplies. The values are:", count);
+
+	for (k, value) in bins.iter().zip(values.iter()) {
+
+		println!("p(w^{}) = {}", k, value);
+
+	}
+
+}
+
+fn evaluateAtPoints(poly: &Polynomial){
+
+	//a list of points has the same file format as a polynomial's coefficients
+	println!("The points are read in the same format as coefficients.");
+	let points = readFromFile();
+
+	println!("Evaluating polynomial using a subproduct tree...");
+
+	let (values, count) = poly.evaluateAtPoints(&points);
+
+	println!("Done. {} mulitplies. The values are:", count);
+
+	for (point, value) in points.iter().zip(values.iter()) {
+
+		println!("p({}) = {}", point, value);
+
+	}
+
+}
+
+fn interpolate(values: &[Complex64], current: &Polynomial) -> Polynomial {
+
+	if values.is_empty() {
+
+		println!("There are no values for the current polynomial. Evaluate it first.");
+		return current.clone();
+
+	}
+
+	println!("Interpolating the current values using the inverse FFT...");
+
+	let (poly, _) = Polynomial::interpolateFFT(values);
+
+	println!("Done. The coefficients are: {}", poly.print());
+
+	return poly;
+
+}
+
+fn multiply(poly: &Polynomial) -> Polynomial {
+
+	let other = readFromFile();
+
+	println!("Multiplying polynomials...");
+
+	let schoolbookStart = Instant::now();
+
+	let (product, schoolbookCount) = poly.multiplySchoolbook(&other);
+
+	let schoolbookEnd = Instant::now();
+
+	let (_, fftCount) = poly.multiplyFFT(&other);
+
+	let fftEnd = Instant::now();
+
+	let schoolbookElapsed 	= schoolbookEnd.duration_since(schoolbookStart);
+	let fftElapsed 			= fftEnd.duration_since(schoolbookEnd);
+
+	println!("Done. Results:");
+	println!("Schoolbook:     {} mulitplies in {}s {}ns", schoolbookCount, schoolbookElapsed.as_secs(), schoolbookElapsed.subsec_nanos());
+	println!("FFT:            {} mulitplies in {}s {}ns", fftCount, fftElapsed.as_secs(), fftElapsed.subsec_nanos());
+	println!("The product is: {}", product.print());
+
+	return product;
+
+}
+
+fn compose(poly: &Polynomial) -> Polynomial {
+
+	println!("The inner polynomial q is read from a file.");
+	let inner = readFromFile();
+
+	println!("Composing polynomials...");
+
+	let hornerStart = Instant::now();
+
+	let (composition, hornerCount) = poly.composeHorner(&inner);
+
+	let hornerEnd = Instant::now();
+
+	let (_, fftCount) = poly.composeFFT(&inner);
+
+	let fftEnd = Instant::now();
+
+	let hornerElapsed 	= hornerEnd.duration_since(hornerStart);
+	let fftElapsed 		= fftEnd.duration_since(hornerEnd);
+
+	println!("Done. Results:");
+	println!("Horner's:       {} mulitplies in {}s {}ns", hornerCount, hornerElapsed.as_secs(), hornerElapsed.subsec_nanos());
+	println!("FFT:            {} mulitplies in {}s {}ns", fftCount, fftElapsed.as_secs(), fftElapsed.subsec_nanos());
+	println!("The composition is: {}", composition.print());
+
+	return composition;
+
+}
+
+fn differentiate(poly: &Polynomial) -> Polynomial {
+
+	println!("Differentiating polynomial...");
+
+	let (derivative, count) = poly.derivative();
+
+	println!("Done. {} mulitplies. The derivative is: {}", count, derivative.print());
+
+	return derivative;
+
+}
+
+fn integrate(poly: &Polynomial) -> Polynomial {
+
+	println!("The constant of integration is the antiderivative's value at zero.");
+	let constant = readPoint();
+
+	println!("Integrating polynomial...");
+
+	let (integral, count) = poly.integral(constant);
+
+	println!("Done. {} mulitplies. The antiderivative is: {}", count, integral.print());
+
+	return integral;
+
+}
+
+fn taylorShift(poly: &Polynomial) -> Polynomial {
+
+	println!("The shift a is entered as a point.");
+	let a = readPoint();
+
+	println!("Shifting polynomial...");
+
+	let hornerStart = Instant::now();
+
+	let (shifted, hornerCount) = poly.taylorShiftHorner(a);
+
+	let hornerEnd = Instant::now();
+
+	let (_, fftCount) = poly.taylorShiftFFT(a);
+
+	let fftEnd = Instant::now();
+
+	let hornerElapsed 	= hornerEnd.duration_since(hornerStart);
+	let fftElapsed 		= fftEnd.duration_since(hornerEnd);
+
+	println!("Done. Results:");
+	println!("Horner's:       {} mulitplies in {}s {}ns", hornerCount, hornerElapsed.as_secs(), hornerElapsed.subsec_nanos());
+	println!("FFT:            {} mulitplies in {}s {}ns", fftCount, fftElapsed.as_secs(), fftElapsed.subsec_nanos());
+	println!("The shifted polynomial is: {}", shifted.print());
+
+	return shifted;
+
+}
+
+fn readIntFromFile() -> IntPolynomial {
+
+	let mut filename = String::new();
+	print!("Enter a filename of integer coefficients to read: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut filename).expect("stdin is broken.");
+
+	let poly = IntPolynomial::readFromFile(&filename);
+
+	if let Err(e) = poly {
+
+		println!("Error reading file: {}", e);
+		return readIntFromFile();
+
+	} else {
+
+		return poly.unwrap();
+
+	}
+
+}
+
+fn evaluateNTT(){
+
+	let poly = readIntFromFile();
+
+	println!("Evaluating polynomial using the NTT...");
+
+	match poly.evaluateAtNTT() {
+
+		Ok((values, count)) => println!("Done. {} mulitplies. The values mod {} are: {:?}", count, ntt::MODULUS, values),
+		Err(e) 				=> println!("Could not evaluate: {}", e)
+
+	}
+
+}
+
+fn multiplyNTT(){
+
+	let left = readIntFromFile();
+	let right = readIntFromFile();
+
+	println!("Multiplying polynomials using the NTT...");
+
+	match left.multiplyNTT(&right) {
+
+		Ok((product, count)) 	=> println!("Done. {} mulitplies. The product is: {}", count, product.print()),
+		Err(e) 					=> println!("Could not multiply: {}", e)
+
+	}
+
+}
+
+fn readRationalFromFile() -> Polynomial<GaussianRational> {
+
+	let mut filename = String::new();
+	print!("Enter a filename of rational coefficients to read: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut filename).expect("stdin is broken.");
+
+	let poly = rational::readFromFile(&filename);
+
+	if let Err(e) = poly {
+
+		println!("Error reading file: {}", e);
+		return readRationalFromFile();
+
+	} else {
+
+		return poly.unwrap();
+
+	}
+
+}
+
+fn readRationalPoint() -> GaussianRational {
+
+	let mut input = String::new();
+
+	print!("Enter a point as real,imaginary fractions, like 1/3,-2/7: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut input).expect("stdin is broken.");
+
+	match input.parse() {
+
+		Ok(point) 	=> return point,
+		Err(e) 		=> {
+
+			println!("Invalid point: {}", e);
+			return readRationalPoint();
+
+		}
+
+	}
+
+}
+
+fn evaluateRational(){
+
+	let poly = readRationalFromFile();
+	println!("The rational polynomial is: {}", rational::printTerms(&poly));
+
+	let point = readRationalPoint();
+
+	println!("Evaluating polynomial exactly using Horner's method...");
+
+	let (value, count) = poly.evaluateAtHorner(point);
+
+	println!("Done. {} mulitplies. The value is: {}", count, value);
+
+}
+
+fn readSparseFromFile() -> SparsePolynomial {
+
+	let mut filename = String::new();
+	print!("Enter a filename of sparse terms to read: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut filename).expect("stdin is broken.");
+
+	let poly = SparsePolynomial::readFromFile(&filename);
+
+	if let Err(e) = poly {
+
+		println!("Error reading file: {}", e);
+		return readSparseFromFile();
+
+	} else {
+
+		return poly.unwrap();
+
+	}
+
+}
+
+fn writeSparseToFile(poly: &Polynomial){
+
+	let mut filename = String::new();
+	print!("Enter a filename to write the sparse terms to: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut filename).expect("stdin is broken.");
+
+	let result = SparsePolynomial::from(poly).writeToFile(&filename);
+
+	if let Err(e) = result {
+
+		println!("File not written: {}", e);
+
+	} else {
+
+		println!("File written successfully to \"{}\"", filename);
+
+	}
+
+}
+
+fn evaluateSparse(){
+
+	let poly = readSparseFromFile();
+	println!("The sparse polynomial has {} terms: {}", poly.termCount(), poly.print());
+
+	let point = readPoint();
+
+	println!("Evaluating polynomial using the sparse method...");
+
+	let (value, count) = poly.evaluateAt(point);
+
+	println!("Done. {} mulitplies. The value is: {}", count, value);
+
+}
+
+fn readBivariateFromFile() -> BivariatePolynomial {
+
+	let mut filename = String::new();
+	print!("Enter a filename of bivariate coefficients to read: ");
+	let _ = io::stdout().flush();
+	io::stdin().read_line(&mut filename).expect("stdin is broken.");
+
+	let poly = BivariatePolynomial::readFromFile(&filename);
+
+	if let Err(e) = poly {
+
+		println!("Error reading file: {}", e);
+		return readBivariateFromFile();
+
+	} else {
+
+		return poly.unwrap();
+
+	}
+
+}
+
+fn evaluateBivariate(){
+
+	let poly = readBivariateFromFile();
+	println!("The bivariate polynomial is: {}", poly.print());
+
+	println!("Evaluating polynomial on the {} x {} grid using the 2D FFT...", poly.rows(), poly.cols());
+	let (values, fftCount) = poly.evaluateAtFFT();
+
+	println!("Cross-checking with nested Horner's method and the naive method...");
+	let (horner, hornerCount) = poly.evaluateOnGrid(BivariatePolynomial::evaluateAtHorner);
+	let (_, naiveCount) = poly.evaluateOnGrid(BivariatePolynomial::evaluateAtNaive);
+
+	let mut largest: f64 = 0.0;
+
+	for (row, hornerRow) in values.iter().zip(horner.iter()) {
+
+		for (value, hornerValue) in row.iter().zip(hornerRow.iter()) {
+
+			largest = largest.max((value - hornerValue).norm());
+
+		}
+
+	}
+
+	println!("Done. Multiplies: 2D FFT {}, nested Horner {}, naive {}.", fftCount, hornerCount, naiveCount);
+	println!("The largest difference from nested Horner's method is {:e}.", largest);
+
+	for (j, row) in values.iter().enumerate() {
+
+		println!("x = w^{}: {}", j, printTerms(row));
+
+	}
 
 }
 
@@ -225,6 +1024,7 @@ fn benchmarkAlgorithms(poly: &Polynomial){
 	println!("Generating roots of unity...");
 	let rootsNaive 			= rootsOfUnity(poly.len() as i32);
 	let rootsHorner 		= rootsOfUnity(poly.len() as i32);
+	let rootsEstrin 		= rootsOfUnity(poly.len() as i32);
 	let rootsNaiveImproved 	= rootsOfUnity(poly.len() as i32);
 
 	println!("Benchmarking algorithms...");
@@ -247,6 +1047,14 @@ fn benchmarkAlgorithms(poly: &Polynomial){
 
 	let hornerEnd = Instant::now();
 
+	for root in rootsEstrin {
+
+		let (_, _) = poly.evaluateAtEstrin(root);
+
+	}
+
+	let estrinEnd = Instant::now();
+
 	for root in rootsNaiveImproved {
 
 		let (_, _) = poly.evaluateAtNaiveImproved(root);
@@ -261,15 +1069,54 @@ fn benchmarkAlgorithms(poly: &Polynomial){
 
 	let naiveElapsed 			= naiveEnd.duration_since(naiveStart);
 	let hornerElapsed 			= hornerEnd.duration_since(naiveEnd);
-	let naiveImprovedElapsed 	= naiveImprovedEnd.duration_since(hornerEnd);
+	let estrinElapsed 			= estrinEnd.duration_since(hornerEnd);
+	let naiveImprovedElapsed 	= naiveImprovedEnd.duration_since(estrinEnd);
 	let fftElapsed 				= fftEnd.duration_since(naiveImprovedEnd);
 
 	println!("Done. Results:");
 	println!("Naive:          {}s {}ns", naiveElapsed.as_secs(), naiveElapsed.subsec_nanos());
 	println!("Horner's:       {}s {}ns", hornerElapsed.as_secs(), hornerElapsed.subsec_nanos());
+	println!("Estrin's:       {}s {}ns", estrinElapsed.as_secs(), estrinElapsed.subsec_nanos());
 	println!("Naive Improved: {}s {}ns", naiveImprovedElapsed.as_secs(), naiveImprovedElapsed.subsec_nanos());
 	println!("FFT:            {}s {}ns", fftElapsed.as_secs(), fftElapsed.subsec_nanos());
 
+	let threads = thread::available_parallelism().map(|count| count.get()).unwrap_or(1);
+	let parallelStart = Instant::now();
+
+	let (_, _) = poly.evaluateAtFFTParallel(threads);
+
+	let parallelElapsed = Instant::now().duration_since(parallelStart);
+
+	println!("Parallel FFT:   {}s {}ns with {} threads", parallelElapsed.as_secs(), parallelElapsed.subsec_nanos(), poly.parallelFFTThreads(threads));
+
+	//a plan only pays off when it is reused, so only time the evaluation itself
+	if let Ok(plan) = FftPlan::new(poly.len()) {
+
+		let planStart = Instant::now();
+
+		let _ = plan.evaluate(poly);
+
+		let planElapsed = Instant::now().duration_since(planStart);
+
+		println!("FFT Plan:       {}s {}ns", planElapsed.as_secs(), planElapsed.subsec_nanos());
+
+	}
+
+	//with few enough nonzero terms, evaluating only those is worth timing too
+	if isSparse(poly) {
+
+		let sparse = SparsePolynomial::from(poly);
+		let roots = rootsOfUnity(poly.len() as i32);
+		let sparseStart = Instant::now();
+
+		let (_, _) = sparse.evaluateAtPoints(&roots);
+
+		let sparseElapsed = Instant::now().duration_since(sparseStart);
+
+		println!("Sparse:         {}s {}ns", sparseElapsed.as_secs(), sparseElapsed.subsec_nanos());
+
+	}
+
 }
 
 fn displayMultiplyCount(poly: &Polynomial){
@@ -278,12 +1125,14 @@ fn displayMultiplyCount(poly: &Polynomial){
 	println!("Generating roots of unity...");
 	let rootsNaive 			= rootsOfUnity(poly.len() as i32);
 	let rootsHorner 		= rootsOfUnity(poly.len() as i32);
+	let rootsEstrin 		= rootsOfUnity(poly.len() as i32);
 	let rootsNaiveImproved 	= rootsOfUnity(poly.len() as i32);
 
 	println!("Benchmarking algorithms...");
 
 	let mut naiveCount = 0;
 	let mut hornerCount = 0;
+	let mut estrinCount = 0;
 	let mut naiveImprovedCount = 0;
 
 	let naiveStart = Instant::now();
@@ -306,6 +1155,15 @@ fn displayMultiplyCount(poly: &Polynomial){
 
 	let hornerEnd = Instant::now();
 
+	for root in rootsEstrin {
+
+		let (_, count) = poly.evaluateAtEstrin(root);
+		estrinCount += count;
+
+	}
+
+	let estrinEnd = Instant::now();
+
 	for root in rootsNaiveImproved {
 
 		let (_, count) = poly.evaluateAtNaiveImproved(root);
@@ -321,13 +1179,43 @@ fn displayMultiplyCount(poly: &Polynomial){
 
 	let naiveElapsed 			= naiveEnd.duration_since(naiveStart);
 	let hornerElapsed 			= hornerEnd.duration_since(naiveEnd);
-	let naiveImprovedElapsed 	= naiveImprovedEnd.duration_since(hornerEnd);
+	let estrinElapsed 			= estrinEnd.duration_since(hornerEnd);
+	let naiveImprovedElapsed 	= naiveImprovedEnd.duration_since(estrinEnd);
 	let fftElapsed 				= fftEnd.duration_since(naiveImprovedEnd);
 
 	println!("Done. Results:");
 	println!("Naive:          {} mulitplies in {}s {}ns", naiveCount, naiveElapsed.as_secs(), naiveElapsed.subsec_nanos());
 	println!("Horner's:       {} mulitplies in {}s {}ns", hornerCount, hornerElapsed.as_secs(), hornerElapsed.subsec_nanos());
+	println!("Estrin's:       {} mulitplies in {}s {}ns", estrinCount, estrinElapsed.as_secs(), estrinElapsed.subsec_nanos());
 	println!("Naive Improved: {} mulitplies in {}s {}ns", naiveImprovedCount, naiveImprovedElapsed.as_secs(), naiveImprovedElapsed.subsec_nanos());
 	println!("FFT:            {} mulitplies in {}s {}ns", fftCount, fftElapsed.as_secs(), fftElapsed.subsec_nanos());
 
+	//real coefficients took the half length shortcut above, so show what it saved
+	if poly.iter().all(|coeff| coeff.im == 0.0) {
+
+		let complexStart = Instant::now();
+
+		let (_, complexCount) = poly.evaluateAtFFTComplex();
+
+		let complexElapsed = Instant::now().duration_since(complexStart);
+
+		println!("Complex FFT:    {} mulitplies in {}s {}ns", complexCount, complexElapsed.as_secs(), complexElapsed.subsec_nanos());
+
+	}
+
+	//with few enough nonzero terms, evaluating only those is worth counting too
+	if isSparse(poly) {
+
+		let sparse = SparsePolynomial::from(poly);
+		let roots = rootsOfUnity(poly.len() as i32);
+		let sparseStart = Instant::now();
+
+		let (_, sparseCount) = sparse.evaluateAtPoints(&roots);
+
+		let sparseElapsed = Instant::now().duration_since(sparseStart);
+
+		println!("Sparse:         {} mulitplies in {}s {}ns", sparseCount, sparseElapsed.as_secs(), sparseElapsed.subsec_nanos());
+
+	}
+
 }
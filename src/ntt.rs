@@ -0,0 +1,592 @@
+
+use std::cmp;
+use std::convert::TryFrom;
+use std::str;
+use std::fs::File;
+use std::io::Read;
+
+use nom::IResult::*;
+use nom::Err::*;
+
+use poly::{integer, get_min_err, bitReversePermute};
+
+///The prime evaluateAtNTT does all of its arithmetic modulo. MODULUS - 1 is
+///2^23 * 7 * 17, so there are n-th roots of unity for every power of two n up to 2^23.
+pub const MODULUS: u64 = 998244353;
+
+///A prime the NTT can work modulo. The modulus is below 2^30, so the product of two
+///residues fits in a u64, and modulus - 1 has a large power of two factor, so there
+///are roots of unity for every power of two length up to maxLength.
+#[derive(Clone, Copy, Debug)]
+struct NttPrime {
+
+	modulus: u64,
+
+	///generates every nonzero value mod the modulus
+	generator: u64,
+
+	///the longest transform there are roots of unity for
+	maxLength: usize
+
+}
+
+///The prime evaluateAtNTT uses.
+const EVALUATION_PRIME: NttPrime = NttPrime { modulus: MODULUS, generator: 3, maxLength: 1 << 23 };
+
+///The primes multiplyNTT works modulo, as many of them as the product's coefficients
+///need, taken in this order so the longest transforms come first. All six together
+///have over 175 bits, more than any product of two i64 polynomials can need.
+const PRODUCT_PRIMES: [NttPrime; 6] = [
+	NttPrime { modulus: 469762049, generator: 3, maxLength: 1 << 26 },
+	NttPrime { modulus: 167772161, generator: 3, maxLength: 1 << 25 },
+	NttPrime { modulus: 754974721, generator: 11, maxLength: 1 << 24 },
+	NttPrime { modulus: 998244353, generator: 3, maxLength: 1 << 23 },
+	NttPrime { modulus: 985661441, generator: 3, maxLength: 1 << 22 },
+	NttPrime { modulus: 943718401, generator: 7, maxLength: 1 << 22 }
+];
+
+///An integer polynomial is an array of its integer coefficients.
+///poly[0] is the x^0 term, and so on
+pub type IntPolynomial = Vec<i64>;
+
+//Create a function named "signedInteger" that looks for an optional
+//negative sign followed by an integer.
+named!(signedInteger<i64>, chain!(
+    neg: opt!(tag!("-")) ~
+    value: integer ,
+    || if neg.is_some() { -value } else { value }
+));
+
+//Create a function named "intpolyfile" that returns a tuple of an i64 and an IntPolynomial.
+//It is the same as "polyfile", except each coefficient is a single integer
+//on its own line instead of a pair of floating point numbers.
+named!(intpolyfile <(i64, IntPolynomial)>,
+    chain!(
+        deg: integer ~
+        many1!(tag!("\n")) ~
+        coeffs: many0!(complete!(chain!(
+            many0!(tag!("\n")) ~
+            value: signedInteger ,
+            || value
+        ))) ,
+        || {
+            return (deg, coeffs)
+        })
+);
+
+impl NttPrime {
+
+	///Multiplies two residues.
+	fn mul(&self, a: u64, b: u64) -> u64 {
+
+		return (a * b) % self.modulus;
+
+	}
+
+	///Raises base to the exp power by repeated squaring.
+	fn pow(&self, base: u64, exp: u64) -> u64 {
+
+		let mut result = 1;
+		let mut base = base % self.modulus;
+		let mut exp = exp;
+
+		while exp > 0 {
+
+			if exp & 1 == 1 {
+
+				result = self.mul(result, base);
+
+			}
+
+			base = self.mul(base, base);
+			exp >>= 1;
+
+		}
+
+		return result;
+
+	}
+
+	///Calculates the inverse of a nonzero residue, which is its (modulus - 2)-th power.
+	fn inverse(&self, value: u64) -> u64 {
+
+		return self.pow(value, self.modulus - 2);
+
+	}
+
+	///Maps an integer, possibly negative, to its residue.
+	fn toResidue(&self, value: i64) -> u64 {
+
+		return value.rem_euclid(self.modulus as i64) as u64;
+
+	}
+
+	///Calculates the principal n-th root of unity. n must divide modulus - 1.
+	fn rootOfUnity(&self, n: usize) -> u64 {
+
+		return self.pow(self.generator, (self.modulus - 1) / n as u64);
+
+	}
+
+}
+
+///Runs an iterative radix-2 NTT over the residues mod the prime in place, the same
+///way fftInPlace does with complex numbers. The inverse includes the 1/n scaling. The
+///length must be a power of two no longer than the prime's maxLength. Returns the
+///number of multiplications done, one per butterfly.
+fn nttInPlace(values: &mut [u64], inverse: bool, prime: &NttPrime) -> usize {
+
+	let mut count: usize = 0;
+	let n = values.len();
+
+	if n <= 1 {
+
+		return count;
+
+	}
+
+	bitReversePermute(values);
+
+	let mut len = 2;
+
+	while len <= n {
+
+		let half = len / 2;
+		let mut step = prime.rootOfUnity(len);
+
+		if inverse {
+
+			step = prime.inverse(step);
+
+		}
+
+		//unlike the complex version, stepping the twiddle along is exact
+		let mut root = 1;
+
+		for k in 0..half {
+
+			for start in (0..n).step_by(len) {
+
+				let right = prime.mul(root, values[start + k + half]);
+				count += 1;
+
+				let left = values[start + k];
+
+				values[start + k] = (left + right) % prime.modulus;
+				values[start + k + half] = (left + prime.modulus - right) % prime.modulus;
+
+			}
+
+			root = prime.mul(root, step);
+
+		}
+
+		len *= 2;
+
+	}
+
+	if inverse {
+
+		let scale = prime.inverse(n as u64);
+
+		for value in values.iter_mut() {
+
+			*value = prime.mul(*value, scale);
+
+		}
+
+	}
+
+	return count;
+
+}
+
+///Multiplies two integer polynomials mod the prime with the NTT. n is the transform
+///length, already padded so the circular convolution never wraps around. Returns the
+///product's residues and the multiplication count.
+fn convolveMod(left: &[i64], right: &[i64], n: usize, prime: &NttPrime) -> (Vec<u64>, usize) {
+
+	let mut count: usize = 0;
+
+	let mut leftValues: Vec<u64> = left.iter().map(|&coeff| prime.toResidue(coeff)).collect();
+	let mut rightValues: Vec<u64> = right.iter().map(|&coeff| prime.toResidue(coeff)).collect();
+
+	leftValues.resize(n, 0);
+	rightValues.resize(n, 0);
+
+	count += nttInPlace(&mut leftValues, false, prime);
+	count += nttInPlace(&mut rightValues, false, prime);
+
+	for k in 0..n {
+
+		leftValues[k] = prime.mul(leftValues[k], rightValues[k]);
+		count += 1;
+
+	}
+
+	count += nttInPlace(&mut leftValues, true, prime);
+
+	return (leftValues, count);
+
+}
+
+///Works out, for each prime, the inverse mod that prime of the product of the primes
+///before it, which Garner's algorithm divides by at every step.
+fn placeInverses(primes: &[NttPrime]) -> Vec<u64> {
+
+	let mut inverses = Vec::with_capacity(primes.len());
+
+	for (i, prime) in primes.iter().enumerate() {
+
+		let place = primes[..i].iter().fold(1, |place, earlier| prime.mul(place, earlier.modulus % prime.modulus));
+		inverses.push(prime.inverse(place));
+
+	}
+
+	return inverses;
+
+}
+
+///Puts an integer back together from its residues mod the primes with Garner's
+///algorithm. That gives its digits in the mixed radix p0, p0 p1, p0 p1 p2, and so on,
+///and the integer is the one closest to zero with those residues. The caller makes
+///sure the primes' product is more than twice its size, which is enough for the top
+///digit alone to tell whether it is negative. Returns None if it doesn't fit in an i64.
+fn fromResidues(residues: &[u64], primes: &[NttPrime], placeInverses: &[u64]) -> Option<i64> {
+
+	let mut digits: Vec<u64> = Vec::with_capacity(primes.len());
+
+	for (i, prime) in primes.iter().enumerate() {
+
+		//take what the earlier digits add up to mod this prime off of the residue
+		let mut prefix = 0;
+		let mut place = 1;
+
+		for (digit, earlier) in digits.iter().zip(primes.iter()) {
+
+			prefix = (prefix + prime.mul(*digit, place)) % prime.modulus;
+			place = prime.mul(place, earlier.modulus % prime.modulus);
+
+		}
+
+		let difference = (residues[i] + prime.modulus - prefix) % prime.modulus;
+		digits.push(prime.mul(difference, placeInverses[i]));
+
+	}
+
+	//a negative integer x shows up as x + M, where M is the product of the primes, and
+	//M - 1 has every digit at its largest, p - 1, so x is what's left after taking
+	//each digit down by p - 1 and one more off of the end. Either way every term has
+	//the same sign, so once one overflows, the whole thing is too large.
+	let top = primes.len() - 1;
+	let negative = digits[top] > (primes[top].modulus - 1) / 2;
+
+	let mut value: i128 = if negative { -1 } else { 0 };
+	let mut place: Option<i128> = Some(1);
+
+	for (&digit, prime) in digits.iter().zip(primes.iter()) {
+
+		let digit = if negative { digit as i128 - (prime.modulus as i128 - 1) } else { digit as i128 };
+
+		if digit != 0 {
+
+			value = value.checked_add(digit.checked_mul(place?)?)?;
+
+		}
+
+		place = place.and_then(|place| place.checked_mul(prime.modulus as i128));
+
+	}
+
+	return i64::try_from(value).ok();
+
+}
+
+///This trait defines what we can do with an integer polynomial
+pub trait IntPoly {
+
+	///Reads the polynomial from a file with one integer coefficient per line.
+	///Returns the read polynomial on success, or an error message on failure.
+	fn readFromFile(filename: &str) -> Result<IntPolynomial, String>;
+
+	///Generates a pretty looking string to print to the console.
+	fn print(&self) -> String;
+
+	///Evaluates the polynomial exactly at the n roots of unity mod MODULUS using the
+	///Number Theoretic Transform. The length must be a power of two up to 2^23.
+	///Returns the values as residues and the multiplication count, or an error message.
+	fn evaluateAtNTT(&self) -> Result<(Vec<u64>, usize), String>;
+
+	///Multiplies two polynomials exactly using NTTs mod as many primes as the size of
+	///the product's coefficients needs, then puts each coefficient back together from
+	///its residues with the Chinese remainder theorem. Returns the product and the
+	///multiplication count, or an error message if one of the product's coefficients
+	///does not fit in an i64 or the product is too long for the transforms.
+	fn multiplyNTT(&self, other: &IntPolynomial) -> Result<(IntPolynomial, usize), String>;
+
+}
+
+impl IntPoly for IntPolynomial {
+
+	fn readFromFile(filename: &str) -> Result<IntPolynomial, String> {
+
+		let file = File::open(filename.trim());
+		if file.is_err() { return Err("Failed to open file.".to_string()); }
+
+		let mut all_text = String::new();
+		let _ = file.unwrap().read_to_string(&mut all_text);
+
+		let (degrees, poly) = match intpolyfile(all_text.as_bytes()) {
+
+			Done(extra, (deg, pol)) => {
+
+				//there should be nothing left in the file, besides the newlines
+				//the parser stopped in front of
+				let extra_str = str::from_utf8(extra).unwrap().trim();
+
+				if extra_str.len() > 0 {
+
+					return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(extra_str.as_bytes())));
+
+				}
+
+				(deg, pol)
+
+			},
+			Error(Position(_, pos)) => return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(pos))),
+			_ => return Err("Parsing failed, invalid format.".to_string())
+
+		};
+
+		if degrees != poly.len() as i64 {
+
+			return Err(format!("Incorrect number of coefficients, {} instead of {}.", poly.len(), degrees));
+
+		}
+
+		return Ok(poly);
+
+	}
+
+	fn print(&self) -> String {
+
+		let mut string = String::new();
+
+		for (term, coeff) in self.iter().enumerate() {
+
+			string.push_str(&format!("({})(x^{})", coeff, term));
+
+			if term < self.len() - 1 {
+
+				string.push_str(" + ");
+
+			}
+
+		}
+
+		return string;
+
+	}
+
+	fn evaluateAtNTT(&self) -> Result<(Vec<u64>, usize), String> {
+
+		let n = self.len();
+
+		let prime = EVALUATION_PRIME;
+
+		if n > 1 && (!n.is_power_of_two() || n > prime.maxLength) {
+
+			return Err(format!("The NTT needs a power of two length up to {}, not {}.", prime.maxLength, n));
+
+		}
+
+		let mut values: Vec<u64> = self.iter().map(|&coeff| prime.toResidue(coeff)).collect();
+		let count = nttInPlace(&mut values, false, &prime);
+
+		return Ok((values, count));
+
+	}
+
+	fn multiplyNTT(&self, other: &IntPolynomial) -> Result<(IntPolynomial, usize), String> {
+
+		let mut count: usize = 0;
+
+		if self.is_empty() || other.is_empty() {
+
+			return Ok((IntPolynomial::new(), count));
+
+		}
+
+		//every coefficient of the product is a sum of at most min(n, m) products, and it
+		//comes back out of its residues exactly, sign and all, once the product of the
+		//primes is comfortably more than twice that big
+		let largest = |poly: &IntPolynomial| poly.iter().map(|coeff| coeff.unsigned_abs() as u128).max().unwrap();
+		let bound = largest(self).checked_mul(largest(other)).and_then(|bound| bound.checked_mul(cmp::min(self.len(), other.len()) as u128));
+		let neededBits = bound.map_or(f64::INFINITY, |bound| (bound as f64).log2() + 2.0);
+
+		let mut primeCount = 0;
+		let mut bits = 0.0;
+
+		while primeCount == 0 || (primeCount < PRODUCT_PRIMES.len() && bits <= neededBits) {
+
+			bits += (PRODUCT_PRIMES[primeCount].modulus as f64).log2();
+			primeCount += 1;
+
+		}
+
+		let primes = &PRODUCT_PRIMES[..primeCount];
+
+		//pad both so the circular convolution never wraps around
+		let len = self.len() + other.len() - 1;
+		let n = len.next_power_of_two();
+
+		if n > primes.iter().map(|prime| prime.maxLength).min().unwrap() {
+
+			return Err(format!("The product is too long for the NTT, {} coefficients.", len));
+
+		}
+
+		let mut residues = Vec::with_capacity(primes.len());
+
+		for prime in primes {
+
+			let (values, convolveCount) = convolveMod(self, other, n, prime);
+			residues.push(values);
+			count += convolveCount;
+
+		}
+
+		let placeInverses = placeInverses(primes);
+		let mut product = IntPolynomial::with_capacity(len);
+
+		for k in 0..len {
+
+			let coeffResidues: Vec<u64> = residues.iter().map(|values| values[k]).collect();
+
+			match fromResidues(&coeffResidues, primes, &placeInverses) {
+
+				Some(coeff) => product.push(coeff),
+				None 		=> return Err(format!("The product's x^{} coefficient is too large to fit in an i64.", k))
+
+			}
+
+		}
+
+		return Ok((product, count));
+
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	///Evaluates the polynomial at x mod MODULUS with Horner's method.
+	fn hornerMod(poly: &IntPolynomial, x: u64) -> u64 {
+
+		let mut sum = 0;
+
+		for &coeff in poly.iter().rev() {
+
+			sum = (EVALUATION_PRIME.mul(sum, x) + EVALUATION_PRIME.toResidue(coeff)) % MODULUS;
+
+		}
+
+		return sum;
+
+	}
+
+	#[test]
+	fn test_read_int_file(){
+
+		let result = IntPolynomial::readFromFile("./data/int_valid.txt").unwrap();
+		assert_eq!(result, vec![1, -2, 3, -4]);
+
+	}
+
+	#[test]
+	fn test_read_int_file_invalid_has_junk(){
+
+		let result = IntPolynomial::readFromFile("./data/int_invalid_has_junk.txt");
+		assert_eq!(result, Err("Parsing failed. Invalid syntax \'junk\' in file.".to_string()));
+
+	}
+
+	#[test]
+	fn test_evaluate_ntt(){
+
+		let poly = IntPolynomial::readFromFile("./data/int_valid.txt").unwrap();
+		let (values, count) = poly.evaluateAtNTT().unwrap();
+		let root = EVALUATION_PRIME.rootOfUnity(4);
+
+		for (k, &value) in values.iter().enumerate() {
+
+			assert_eq!(value, hornerMod(&poly, EVALUATION_PRIME.pow(root, k as u64)));
+
+		}
+
+		assert_eq!(count, 4);
+		assert_eq!(vec![1, 2, 3].evaluateAtNTT(), Err("The NTT needs a power of two length up to 8388608, not 3.".to_string()));
+
+	}
+
+	#[test]
+	fn test_multiply_ntt(){
+
+		//coefficients in the hundreds, both positive and negative
+		let left: IntPolynomial = (0..300).map(|i| (i * 7919 % 2001) - 1000).collect();
+		let right: IntPolynomial = (0..200).map(|i| (i * 104729 % 1001) - 500).collect();
+
+		let mut answer = vec![0; left.len() + right.len() - 1];
+
+		for (i, a) in left.iter().enumerate() {
+
+			for (j, b) in right.iter().enumerate() {
+
+				answer[i + j] += a * b;
+
+			}
+
+		}
+
+		let (product, _) = left.multiplyNTT(&right).unwrap();
+		assert_eq!(product, answer);
+
+		//coefficients near 2^28 need three primes, and the schoolbook answer needs i128
+		let left: IntPolynomial = (0..100).map(|i: i64| (i * 2654435761 % 536870909) - (1 << 28)).collect();
+		let right: IntPolynomial = (0..100).map(|i: i64| (i * 40503 * 40503 % 536870923) - (1 << 28)).collect();
+
+		let mut answer = vec![0i128; left.len() + right.len() - 1];
+
+		for (i, &a) in left.iter().enumerate() {
+
+			for (j, &b) in right.iter().enumerate() {
+
+				answer[i + j] += a as i128 * b as i128;
+
+			}
+
+		}
+
+		let (product, _) = left.multiplyNTT(&right).unwrap();
+		assert_eq!(product.iter().map(|&coeff| coeff as i128).collect::<Vec<i128>>(), answer);
+
+		//products right up against the i64 limit, and ones that cancel out back down
+		//to fit even though the bound doesn't
+		let (product, _) = vec![3_000_000_000, -7].multiplyNTT(&vec![3_000_000_000, 5]).unwrap();
+		assert_eq!(product, vec![9_000_000_000_000_000_000, -6_000_000_000, -35]);
+
+		let (product, _) = vec![i64::MAX, i64::MAX].multiplyNTT(&vec![1, -1]).unwrap();
+		assert_eq!(product, vec![i64::MAX, 0, -i64::MAX]);
+
+		let (product, _) = vec![1 << 40, 1 << 21, 1].multiplyNTT(&vec![1 << 20, 1]).unwrap();
+		assert_eq!(product, vec![1 << 60, (1 << 41) + (1 << 40), (1 << 21) + (1 << 20), 1]);
+
+		let result = vec![4_000_000_000].multiplyNTT(&vec![-4_000_000_000]);
+		assert_eq!(result, Err("The product's x^0 coefficient is too large to fit in an i64.".to_string()));
+
+	}
+
+}
@@ -3,8 +3,11 @@ use std::cmp;
 use std::str;
 use std::str::FromStr;
 use std::slice;
-use std::f64::consts::PI;
+use std::f64::consts::{E, PI, SQRT_2};
 use std::fs::File;
+use std::thread;
+use std::iter::FromIterator;
+use std::ops::{Add, Sub, Mul, Neg, Deref, DerefMut};
 use std::io::{Read, Write, BufWriter};
 
 use nom::digit;
@@ -15,13 +18,270 @@ use rand::distributions::IndependentSample;
 use rand::distributions::range::Range;
 use rand::thread_rng;
 
-///All a polynomial is is an array of its complex coefficients.
-///poly[0] is the x^0 term, and so on
-pub type Polynomial = Vec<Complex64>;
+use scalar::{Scalar, RootOfUnity};
+
+///All a polynomial is is an array of its coefficients, which are complex unless
+///another Scalar type is given. poly[0] is the x^0 term, and so on. It derefs to a
+///slice of the coefficients, so indexing and iterating work, but the length only
+///changes through the arithmetic operators.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polynomial<T = Complex64>(Vec<T>);
+
+impl<T> Polynomial<T> {
+
+	///Creates the empty polynomial, with no coefficients.
+	pub fn new() -> Polynomial<T> {
+
+		return Polynomial(Vec::new());
+
+	}
+
+}
+
+impl<T: Scalar> Polynomial<T> {
+
+	///Pads with zeros or cuts off the top terms so there are len coefficients.
+	fn setLength(&mut self, len: usize) {
+
+		self.0.resize(len, T::zero());
+
+	}
+
+}
+
+impl<T> Default for Polynomial<T> {
+
+	fn default() -> Polynomial<T> {
+
+		return Polynomial::new();
+
+	}
+
+}
+
+impl<T> From<Vec<T>> for Polynomial<T> {
+
+	fn from(coeffs: Vec<T>) -> Polynomial<T> {
+
+		return Polynomial(coeffs);
+
+	}
+
+}
+
+impl<T> From<Polynomial<T>> for Vec<T> {
+
+	fn from(poly: Polynomial<T>) -> Vec<T> {
+
+		return poly.0;
+
+	}
+
+}
+
+impl<T> FromIterator<T> for Polynomial<T> {
+
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Polynomial<T> {
+
+		return Polynomial(iter.into_iter().collect());
+
+	}
+
+}
+
+impl<'a, T> IntoIterator for &'a Polynomial<T> {
+
+	type Item = &'a T;
+	type IntoIter = slice::Iter<'a, T>;
+
+	fn into_iter(self) -> slice::Iter<'a, T> {
+
+		return self.0.iter();
+
+	}
+
+}
+
+impl<T> Deref for Polynomial<T> {
+
+	type Target = [T];
+
+	fn deref(&self) -> &[T] {
+
+		return &self.0;
+
+	}
+
+}
+
+impl<T> DerefMut for Polynomial<T> {
+
+	fn deref_mut(&mut self) -> &mut [T] {
+
+		return &mut self.0;
+
+	}
+
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for Polynomial<T> {
+
+	fn eq(&self, other: &Vec<T>) -> bool {
+
+		return &self.0 == other;
+
+	}
+
+}
+
+///Adds the coefficients term by term. The sum is as long as the longer operand,
+///trailing zeros included, so the FFT evaluators see the length they expect.
+impl<T: Scalar> Add<&Polynomial<T>> for &Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn add(self, other: &Polynomial<T>) -> Polynomial<T> {
+
+		let mut sum = if self.len() >= other.len() { self.clone() } else { other.clone() };
+		let shorter = if self.len() >= other.len() { other } else { self };
+
+		for (term, coeff) in shorter.iter().enumerate() {
+
+			sum[term] = sum[term].clone() + coeff.clone();
+
+		}
+
+		return sum;
+
+	}
+
+}
+
+impl<T: Scalar> Sub<&Polynomial<T>> for &Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn sub(self, other: &Polynomial<T>) -> Polynomial<T> {
+
+		return self + &(-other);
+
+	}
+
+}
+
+///Multiplies with multiplyFast, so long operands go through the FFT.
+impl Mul<&Polynomial> for &Polynomial {
+
+	type Output = Polynomial;
+
+	fn mul(self, other: &Polynomial) -> Polynomial {
+
+		return multiplyFast(self, other).0;
+
+	}
+
+}
+
+impl<T: Scalar> Mul<T> for &Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn mul(self, scalar: T) -> Polynomial<T> {
+
+		return self.iter().map(|coeff| coeff.clone() * scalar.clone()).collect();
+
+	}
+
+}
+
+impl<T: Scalar> Neg for &Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn neg(self) -> Polynomial<T> {
+
+		return self.iter().map(|coeff| -coeff.clone()).collect();
+
+	}
+
+}
+
+//the owned versions all forward to the borrowed ones above
+impl<T: Scalar> Add for Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn add(self, other: Polynomial<T>) -> Polynomial<T> {
+
+		return &self + &other;
+
+	}
+
+}
+
+impl<T: Scalar> Sub for Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn sub(self, other: Polynomial<T>) -> Polynomial<T> {
+
+		return &self - &other;
+
+	}
+
+}
+
+impl Mul for Polynomial {
+
+	type Output = Polynomial;
+
+	fn mul(self, other: Polynomial) -> Polynomial {
+
+		return &self * &other;
+
+	}
+
+}
+
+impl<T: Scalar> Mul<T> for Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn mul(self, scalar: T) -> Polynomial<T> {
+
+		return &self * scalar;
+
+	}
+
+}
+
+impl Mul<Polynomial> for Complex64 {
+
+	type Output = Polynomial;
+
+	fn mul(self, poly: Polynomial) -> Polynomial {
+
+		return &poly * self;
+
+	}
+
+}
+
+impl<T: Scalar> Neg for Polynomial<T> {
+
+	type Output = Polynomial<T>;
+
+	fn neg(self) -> Polynomial<T> {
+
+		return -&self;
+
+	}
+
+}
 
 //<black_magic>
 //Create a function named "integer" that looks for an integer
-named!(integer<i64>,
+named!(pub integer<i64>,
 	map_res!(
 		map_res!(
 			digit,
@@ -44,7 +304,7 @@ named!(fractional, complete!(chain!(
 //an optional "fractional" part, which is the function defined above.
 //"fp" creates a new slice covering the entire number. Once it has the slice,
 //it converts the slice to an f64 and returns the value.
-named!(fp<f64>, chain!(
+named!(pub fp<f64>, chain!(
     neg: opt!(tag!("-")) ~
     digits: digit ~
     fract: opt!(fractional),
@@ -79,11 +339,11 @@ named!(polyfile <(i64, Polynomial)>,
             || Complex64::new(re, im)
         ))) ,
         || {
-            return (deg as i64, cmplx)
+            return (deg as i64, Polynomial::from(cmplx))
         })
 );//</black_magic>
 
-fn get_min_err(pos: &[u8]) -> &str {
+pub fn get_min_err(pos: &[u8]) -> &str {
     let len = cmp::min(pos.len(), 7);
     let errstr = str::from_utf8(&pos[0..len]).unwrap();
     let errstr_lines: Vec<&str> = errstr.split("\n").collect();
@@ -100,21 +360,18 @@ fn get_min_err(pos: &[u8]) -> &str {
 ///calculates an individual root of unity given n and k
 fn rootOfUnity(n: i32, k: i32) -> Complex64 {
 
-	let theta = (2.0 * PI * k as f64) / n as f64;
-	let (imag, real) = theta.sin_cos();
-
-	return Complex64::new(real, imag);
+	return Complex64::rootOfUnity(n as usize, k as usize);
 
 }
 
 ///Calculates the n roots of unity using Euler's formula.
-pub fn rootsOfUnity(n: i32) -> Vec<Complex64> {
+pub fn rootsOfUnity<T: RootOfUnity>(n: i32) -> Vec<T> {
 
 	let mut roots = Vec::with_capacity(n as usize);
 
 	for k in 0..n {
 
-		roots.push(rootOfUnity(n, k));
+		roots.push(T::rootOfUnity(n as usize, k as usize));
 
 	}
 
@@ -122,494 +379,3255 @@ pub fn rootsOfUnity(n: i32) -> Vec<Complex64> {
 
 }
 
-///This trait defines what we can do with a polynomial
-pub trait Poly {
+///Swaps the values in place so that the value at index i ends up at the index
+///whose low log2(n) bits are the bits of i in reverse order.
+pub fn bitReversePermute<T>(values: &mut [T]) {
 
-	///Reads the polynomial form a file.
-	///Returns the read polynomial on success, or an error message on failure.
-	fn readFromFile(filename: &String) -> Result<Polynomial, String>;
+	let n = values.len();
+	let mut j = 0;
 
-	///Generates a random polynomial with the given degree withing the given bounds
-	fn random(degree: i32, bounds: f64) -> Result<Polynomial, String>;
+	for i in 1..n {
 
-	///Generates a pretty looking string to print to the consome.
-	fn print(&self) -> String;
+		//add one to j, but counting from the most significant bit down
+		let mut bit = n >> 1;
 
-	///Writes the polynomial to a file.
-	///Returns Ok(()) on success, or a nerror message on failure.
-	fn writeToFile(&self, filename: &String) -> Result<(), String>;
+		while j & bit != 0 {
 
-	///Evaluates the polynomial at the given value using the naive method
-	fn evaluateAtNaive(&self, x: Complex64) -> (Complex64, usize);
+			j ^= bit;
+			bit >>= 1;
 
-	///Evaluates using Horner's method
-	fn evaluateAtHorner(&self, x: Complex64) -> (Complex64, usize);
+		}
 
-	///Evaluates using the improved naive method
-	fn evaluateAtNaiveImproved(&self, x: Complex64) -> (Complex64, usize);
+		j ^= bit;
 
-	///Evaluates using the Fast Fourier Transform
-	fn evaluateAtFFT(&self) -> (Vec<Complex64>, usize);
+		if i < j {
+
+			values.swap(i, j);
+
+		}
+
+	}
 
 }
 
-impl Poly for Polynomial {
+///Runs an iterative radix-2 FFT over the values in place. Returns the number of
+///multiplications done, one per butterfly, the same as the recursive version counted
+///them, or an error message, with the values untouched, if the length is not a
+///power of two.
+fn fftInPlace<T: RootOfUnity>(values: &mut [T]) -> Result<usize, String> {
 
-	fn readFromFile(filename: &String) -> Result<Polynomial, String> {
+	let n = values.len();
 
-		let file = File::open(filename.trim());
-	    if file.is_err() { return Err("Failed to open file.".to_string()); }
+	if n <= 1 {
 
-	    let mut all_text = String::new();
-	    let _ = file.unwrap().read_to_string(&mut all_text);
+		return Ok(0);
 
-	    let polyresults = polyfile(all_text.as_bytes());
-	    let degrees: i64;
-	    let poly: Polynomial;
-	    match polyresults {
-	        Done(extra, (deg, pol)) => {
-	            //convert from raw bytes to a utf8 string slice
-	            let extra_str = str::from_utf8(extra).unwrap();
-	            //there should be nothing left in the file
-	            if extra_str.trim().len() > 0 {
-	                return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(extra)));
-	            }
-	            degrees = deg;
-	            poly = pol;
-	        },
-	        Error(errco) => {
-	            match errco {
-	                Position(_, pos) => return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(pos))),
-	                _ => return Err("Parsing failed, invalid format.".to_string())
-	            }
-	        }
-	        Incomplete(_) => return Err("Parsing failed, invalid format.".to_string()),
-	    }
+	}
 
-	    if degrees < 0 {
-	        return Err("Polynomial degree cannot be negative.".to_string());
-	    } else if degrees != poly.len() as i64 {
-	        println!("{:?} != {:?}\n{:?}", degrees, poly.len(), poly);
-	        return Err(format!("Incorrect number of coefficients, {} instead of {}.", poly.len(), degrees));
-	    }
+	if !n.is_power_of_two() {
 
-	    return Ok(poly);
+		return Err(format!("The radix-2 FFT needs a power of two length, not {}.", n));
+
+	}
+
+	bitReversePermute(values);
+
+	return Ok(butterflyPasses(values));
+
+}
+
+///Runs the inverse transform in place, for any length evaluateAtFFT handles. The
+///inverse transform is the forward transform of the conjugates, conjugated again and
+///divided by n. Returns the number of multiplications done.
+fn inverseFftInPlace(values: &mut [Complex64]) -> usize {
+
+	let conjugates: Polynomial = values.iter().map(|value| value.conj()).collect();
+	let (transformed, count) = conjugates.evaluateAtFFT();
+	let scale = 1.0 / values.len() as f64;
+
+	for (value, transformedValue) in values.iter_mut().zip(transformed) {
+
+		*value = transformedValue.conj() * scale;
+
+	}
+
+	return count;
+
+}
+
+///Runs the butterfly passes of fftInPlace over values that are already in bit
+///reversed order. Returns the number of multiplications done.
+fn butterflyPasses<T: RootOfUnity>(values: &mut [T]) -> usize {
+
+	let mut count: usize = 0;
+	let n = values.len();
+
+	//each pass combines pairs of transforms of length half into one of length len
+	let mut len = 2;
+
+	while len <= n {
+
+		let half = len / 2;
+
+		for k in 0..half {
+
+			let root = T::rootOfUnity(len, k);
+
+			for start in (0..n).step_by(len) {
+
+				let right = root.clone() * values[start + k + half].clone();
+				count += 1;
+
+				let left = values[start + k].clone();
+
+				values[start + k] = left.clone() + right.clone();
+				values[start + k + half] = left - right;
+
+			}
+
+		}
+
+		len *= 2;
+
+	}
+
+	return count;
+
+}
+
+///Polynomials shorter than this are not worth spreading across threads.
+pub const PARALLEL_CUTOFF: usize = 1 << 16;
+
+///Works out how many threads fftInPlaceParallel will split a length n transform
+///across when it is given the requested number. Lengths below PARALLEL_CUTOFF and
+///lengths that are not a power of two only get one, and no length gets more than
+///its square root.
+pub fn parallelThreadCount(n: usize, threads: usize) -> usize {
+
+	if n < PARALLEL_CUTOFF || !n.is_power_of_two() || threads <= 1 {
+
+		return 1;
+
+	}
+
+	//the first passes give each thread n / threads points and the last ones give it
+	//n / threads^2 butterflies from each block, so past the square root of n some
+	//threads would have nothing to do
+	let threads = threads.min(1 << (n.trailing_zeros() / 2));
+
+	//the work is split into equal power of two pieces, so round down to one
+	return (threads + 1).next_power_of_two() / 2;
+
+}
+
+///Runs the same transform as fftInPlace, spread across the given number of threads.
+///The butterflies and their twiddles are exactly the same as the serial version, so
+///the results are bit for bit identical. Returns the number of multiplications done.
+fn fftInPlaceParallel(values: &mut [Complex64], threads: usize) -> usize {
+
+	let n = values.len();
+	let threads = parallelThreadCount(n, threads);
+
+	//parallelThreadCount only splits power of two lengths, so anything else has to
+	//have been caught before here
+	if threads == 1 {
+
+		return fftInPlace(values).unwrap();
+
+	}
+
+	let mut count: usize = 0;
+	let chunkLen = n / threads;
+
+	bitReversePermute(values);
+
+	//the passes up to chunkLen never cross a chunk boundary, so each chunk can do
+	//all of them on its own thread
+	count += thread::scope(|scope| {
+
+		let handles: Vec<_> = values.chunks_mut(chunkLen).map(|chunk| scope.spawn(move || butterflyPasses(chunk))).collect();
+
+		return handles.into_iter().map(|handle| handle.join().unwrap()).sum::<usize>();
+
+	});
+
+	//the rest of the passes have fewer, longer blocks, so split each block's
+	//butterflies up instead
+	let mut len = chunkLen * 2;
+
+	while len <= n {
+
+		let half = len / 2;
+		let pieceLen = half / threads;
+
+		for block in values.chunks_mut(len) {
+
+			let (lows, highs) = block.split_at_mut(half);
+
+			count += thread::scope(|scope| {
+
+				let handles: Vec<_> = lows.chunks_mut(pieceLen).zip(highs.chunks_mut(pieceLen)).enumerate().map(|(piece, (low, high))| {
+
+					scope.spawn(move || {
+
+						let offset = piece * pieceLen;
+
+						for j in 0..low.len() {
+
+							let root = rootOfUnity(len as i32, (offset + j) as i32);
+							let right = root * high[j];
+							let left = low[j];
+
+							low[j] = left + right;
+							high[j] = left - right;
+
+						}
+
+						return low.len();
+
+					})
+
+				}).collect();
+
+				return handles.into_iter().map(|handle| handle.join().unwrap()).sum::<usize>();
+
+			});
+
+		}
+
+		len *= 2;
+
+	}
+
+	return count;
+
+}
+
+///A radix-2 FFT for one length with everything that only depends on the length
+///worked out ahead of time. Build it once and reuse it for every polynomial of that
+///length to skip the sin/cos calls and the bit reversal bookkeeping.
+pub struct FftPlan {
+
+	n: usize,
+
+	///twiddles[k] is the k-th of the n roots of unity, for k below n/2
+	twiddles: Vec<Complex64>,
+
+	///the pairs of indices the bit reversal permutation swaps
+	swaps: Vec<(usize, usize)>
+
+}
+
+impl FftPlan {
+
+	///Builds the plan for polynomials of length n.
+	///Returns an error message if n is not a power of two.
+	pub fn new(n: usize) -> Result<FftPlan, String> {
+
+		if n == 0 || !n.is_power_of_two() {
+
+			return Err(format!("An FFT plan needs a power of two length, not {}.", n));
+
+		}
+
+		let mut twiddles = Vec::with_capacity(n / 2);
+
+		for k in 0..n / 2 {
+
+			twiddles.push(rootOfUnity(n as i32, k as i32));
+
+		}
+
+		//step through the same counter bitReversePermute does to find out what it swaps
+		let mut swaps = Vec::new();
+		let mut j = 0;
+
+		for i in 1..n {
+
+			let mut bit = n >> 1;
+
+			while j & bit != 0 {
+
+				j ^= bit;
+				bit >>= 1;
+
+			}
+
+			j ^= bit;
+
+			if i < j {
+
+				swaps.push((i, j));
+
+			}
+
+		}
+
+		return Ok(FftPlan { n, twiddles, swaps });
+
+	}
+
+	///Does the same transform as fftInPlace using the cached tables. Returns the
+	///number of multiplications done, or an error message, with the values untouched,
+	///if they are not the plan's length.
+	pub fn transform(&self, values: &mut [Complex64]) -> Result<usize, String> {
+
+		if values.len() != self.n {
+
+			return Err(format!("This plan is for length {}, not {}.", self.n, values.len()));
+
+		}
+
+		let mut count: usize = 0;
+		let n = self.n;
+
+		for &(i, j) in &self.swaps {
+
+			values.swap(i, j);
+
+		}
+
+		let mut len = 2;
+
+		while len <= n {
+
+			let half = len / 2;
+			let step = n / len;
+
+			for start in (0..n).step_by(len) {
+
+				for k in 0..half {
+
+					let right = self.twiddles[k * step] * values[start + k + half];
+					count += 1;
+
+					let left = values[start + k];
+
+					values[start + k] = left + right;
+					values[start + k + half] = left - right;
+
+				}
+
+			}
+
+			len *= 2;
+
+		}
+
+		return Ok(count);
+
+	}
+
+	///Undoes transform, including the 1/n scaling. Returns the number of
+	///multiplications done, or an error message if the values are not the plan's length.
+	pub fn inverseTransform(&self, values: &mut [Complex64]) -> Result<usize, String> {
+
+		if values.len() != self.n {
+
+			return Err(format!("This plan is for length {}, not {}.", self.n, values.len()));
+
+		}
+
+		for value in values.iter_mut() {
+
+			*value = value.conj();
+
+		}
+
+		let count = self.transform(values)?;
+		let scale = 1.0 / self.n as f64;
+
+		for value in values.iter_mut() {
+
+			*value = value.conj() * scale;
+
+		}
+
+		return Ok(count);
+
+	}
+
+	///Evaluates the polynomial at the n roots of unity, the same as evaluateAtFFT.
+	///Returns the values and the multiplication count, or an error message if the
+	///polynomial is not the plan's length.
+	pub fn evaluate(&self, poly: &Polynomial) -> Result<(Vec<Complex64>, usize), String> {
+
+		let mut values = poly.to_vec();
+		let count = self.transform(&mut values)?;
+
+		return Ok((values, count));
+
+	}
+
+}
+
+///Factors n into the radices the mixed-radix FFT has butterflies for.
+///Returns None if n has any other prime factor.
+fn radixFactors(n: usize) -> Option<Vec<usize>> {
+
+	let mut factors = Vec::new();
+	let mut remaining = n;
+
+	for &radix in &[7, 5, 3, 2] {
+
+		while remaining % radix == 0 {
+
+			factors.push(radix);
+			remaining /= radix;
+
+		}
+
+	}
+
+	if remaining == 1 {
+
+		return Some(factors);
+
+	} else {
+
+		return None;
+
+	}
+
+}
+
+///Multiplies by i, which is just a swap and a sign change.
+fn timesI(x: Complex64) -> Complex64 {
+
+	return Complex64::new(-x.im, x.re);
+
+}
+
+///Does a 2 point DFT in place. No multiplications are needed.
+fn butterfly2(t: &mut [Complex64]) -> usize {
+
+	let (a, b) = (t[0], t[1]);
+
+	t[0] = a + b;
+	t[1] = a - b;
+
+	return 0;
+
+}
+
+///The tables the mixed-radix FFT looks its constants up in, worked out once per
+///transform the same way FftPlan caches the radix-2 twiddles.
+struct MixedRadixTables {
+
+	///w_n^j for every j below the full length n. A stage of length len divides n, so
+	///its twiddle w_len^j is twiddles[j * n / len].
+	twiddles: Vec<Complex64>,
+
+	///the p-th roots of unity each butterfly needs, indexed by p. Only 3, 5 and 7
+	///have any, since the 2 point butterfly has no constants.
+	radixRoots: Vec<Vec<Complex64>>
+
+}
+
+impl MixedRadixTables {
+
+	fn new(n: usize) -> MixedRadixTables {
+
+		let twiddles = (0..n).map(|j| rootOfUnity(n as i32, j as i32)).collect();
+		let mut radixRoots = vec![Vec::new(); 8];
+
+		for &p in &[3, 5, 7] {
+
+			radixRoots[p] = (0..p).map(|r| rootOfUnity(p as i32, r as i32)).collect();
+
+		}
+
+		return MixedRadixTables { twiddles, radixRoots };
+
+	}
+
+}
+
+///Does a 3 point DFT in place. The two outputs past the first share the same real
+///part and have opposite imaginary parts, so there are only two constant multiplies.
+fn butterfly3(t: &mut [Complex64], roots: &[Complex64]) -> usize {
+
+	let cos1 = -0.5;
+	let sin1 = roots[1].im;
+
+	let sum = t[1] + t[2];
+	let diff = t[1] - t[2];
+
+	let real = t[0] + sum * cos1;
+	let imag = timesI(diff * sin1);
+
+	t[0] = t[0] + sum;
+	t[1] = real + imag;
+	t[2] = real - imag;
+
+	return 2;
+
+}
+
+///Does a 5 point DFT in place by pairing up inputs 1 and 4 and inputs 2 and 3.
+fn butterfly5(t: &mut [Complex64], roots: &[Complex64]) -> usize {
+
+	let (sin1, cos1) = (roots[1].im, roots[1].re);
+	let (sin2, cos2) = (roots[2].im, roots[2].re);
+
+	let sum1 = t[1] + t[4];
+	let diff1 = t[1] - t[4];
+	let sum2 = t[2] + t[3];
+	let diff2 = t[2] - t[3];
+
+	let real1 = t[0] + sum1 * cos1 + sum2 * cos2;
+	let imag1 = timesI(diff1 * sin1 + diff2 * sin2);
+	let real2 = t[0] + sum1 * cos2 + sum2 * cos1;
+	let imag2 = timesI(diff1 * sin2 - diff2 * sin1);
+
+	t[0] = t[0] + sum1 + sum2;
+	t[1] = real1 + imag1;
+	t[4] = real1 - imag1;
+	t[2] = real2 + imag2;
+	t[3] = real2 - imag2;
+
+	return 8;
+
+}
+
+///Does a 7 point DFT in place by pairing up inputs q and 7 - q.
+fn butterfly7(t: &mut [Complex64], roots: &[Complex64]) -> usize {
+
+	let mut count: usize = 0;
+	let mut sums = [Complex64::new(0.0, 0.0); 4];
+	let mut diffs = [Complex64::new(0.0, 0.0); 4];
+
+	for q in 1..4 {
+
+		sums[q] = t[q] + t[7 - q];
+		diffs[q] = t[q] - t[7 - q];
+
+	}
+
+	let first = t[0];
+	t[0] = first + sums[1] + sums[2] + sums[3];
+
+	for s in 1..4 {
+
+		let mut real = first;
+		let mut imag = Complex64::new(0.0, 0.0);
+
+		for q in 1..4 {
+
+			let root = roots[(q * s) % 7];
+
+			real = real + sums[q] * root.re;
+			imag = imag + diffs[q] * root.im;
+			count += 2;
+
+		}
+
+		t[s] = real + timesI(imag);
+		t[7 - s] = real - timesI(imag);
+
+	}
+
+	return count;
+
+}
+
+///Runs a recursive mixed-radix Cooley-Tukey FFT. The output is the transform of
+///input[0], input[stride], input[2 * stride], ... and its length is the product of the
+///factors. tables has to have been built for the length of the whole transform.
+///Returns the number of multiplications done, counting one per twiddle and whatever
+///the butterflies report.
+fn mixedRadix(input: &[Complex64], stride: usize, factors: &[usize], output: &mut [Complex64], tables: &MixedRadixTables) -> usize {
+
+	let mut count: usize = 0;
+	let n = output.len();
+
+	if n == 1 {
+
+		output[0] = input[0];
+		return count;
+
+	}
+
+	//split into p interleaved sequences of length m and transform each one
+	let p = factors[0];
+	let m = n / p;
+
+	for q in 0..p {
+
+		count += mixedRadix(&input[q * stride..], stride * p, &factors[1..], &mut output[q * m..(q + 1) * m], tables);
+
+	}
+
+	//combine the k-th value of each sequence with a p point butterfly
+	let mut t = [Complex64::new(0.0, 0.0); 7];
+	let step = tables.twiddles.len() / n;
+	let roots = &tables.radixRoots[p];
+
+	for k in 0..m {
+
+		t[0] = output[k];
+
+		for q in 1..p {
+
+			t[q] = tables.twiddles[((q * k) % n) * step] * output[q * m + k];
+			count += 1;
+
+		}
+
+		count += match p {
+
+			2 => butterfly2(&mut t[..2]),
+			3 => butterfly3(&mut t[..3], roots),
+			5 => butterfly5(&mut t[..5], roots),
+			7 => butterfly7(&mut t[..7], roots),
+			_ => unreachable!("No butterfly for radix {}.", p)
+
+		};
+
+		for s in 0..p {
+
+			output[k + s * m] = t[s];
+
+		}
+
+	}
+
+	return count;
+
+}
+
+///Evaluates real coefficients at the n roots of unity for an even n. Pairs of
+///coefficients are packed into one complex number each, so only an n/2 point
+///transform is needed, and the two halves are pulled back apart afterwards using
+///the symmetry of transforms of real values. Returns the values and the number
+///of multiplications done. halfTransform is what does the n/2 point transform.
+fn realFFT<F>(coeffs: &[Complex64], halfTransform: F) -> (Vec<Complex64>, usize)
+	where F: Fn(&Polynomial) -> (Vec<Complex64>, usize) {
+
+	let n = coeffs.len();
+	let half = n / 2;
+
+	//the evens go in the real parts and the odds in the imaginary parts
+	let packed: Polynomial = (0..half).map(|j| Complex64::new(coeffs[2 * j].re, coeffs[2 * j + 1].re)).collect();
+
+	let (packedValues, mut count) = halfTransform(&packed);
+	let mut values = vec![Complex64::new(0.0, 0.0); n];
+
+	for k in 0..half {
+
+		//Z[k] = E[k] + iO[k] and conj(Z[n/2 - k]) = E[k] - iO[k], since E and O are
+		//transforms of real values
+		let z = packedValues[k];
+		let mirror = packedValues[(half - k) % half].conj();
+
+		let even = (z + mirror) * 0.5;
+		let odd = timesI(mirror - z) * 0.5;
+
+		let right = rootOfUnity(n as i32, k as i32) * odd;
+		count += 1;
+
+		values[k] = even + right;
+		values[k + half] = even - right;
+
+	}
+
+	return (values, count);
+
+}
+
+///Evaluates the coefficients at the n roots of unity for any n using Bluestein's
+///chirp-z algorithm. It rewrites the transform as a convolution, which is done with
+///power of two FFTs. Returns the values and the number of multiplications done.
+fn bluestein(coeffs: &[Complex64]) -> (Vec<Complex64>, usize) {
+
+	let mut count: usize = 0;
+	let n = coeffs.len();
+	let m = (2 * n - 1).next_power_of_two();
+
+	//since jk = (j^2 + k^2 - (k - j)^2) / 2, the chirp w^(j^2 / 2) turns the transform
+	//into a convolution. j^2 is reduced mod 2n first so theta stays small and accurate.
+	let mut chirp = Vec::with_capacity(n);
+
+	for j in 0..n {
+
+		let jSquared = ((j as u64 * j as u64) % (2 * n as u64)) as i32;
+		chirp.push(rootOfUnity(2 * n as i32, jSquared));
+
+	}
+
+	let mut left = vec![Complex64::new(0.0, 0.0); m];
+	let mut right = vec![Complex64::new(0.0, 0.0); m];
+
+	for j in 0..n {
+
+		left[j] = coeffs[j] * chirp[j];
+		count += 1;
+
+	}
+
+	//the convolution is circular, so the negative indices wrap to the end
+	right[0] = chirp[0].conj();
+
+	for j in 1..n {
+
+		right[j] = chirp[j].conj();
+		right[m - j] = chirp[j].conj();
+
+	}
+
+	//all three transforms are the same length, so they can share one plan, and
+	//every one of them is the plan's length
+	let plan = FftPlan::new(m).unwrap();
+
+	count += plan.transform(&mut left).unwrap();
+	count += plan.transform(&mut right).unwrap();
+
+	for j in 0..m {
+
+		left[j] = left[j] * right[j];
+		count += 1;
+
+	}
+
+	count += plan.inverseTransform(&mut left).unwrap();
+
+	let mut values = Vec::with_capacity(n);
+
+	for k in 0..n {
+
+		values.push(chirp[k] * left[k]);
+		count += 1;
+
+	}
+
+	return (values, count);
+
+}
+
+///Below this many coefficients a schoolbook multiply beats going through the FFT.
+const MULTIPLY_CUTOFF: usize = 32;
+
+///Multiplies with whichever of the schoolbook or FFT methods suits the sizes.
+fn multiplyFast(left: &Polynomial, right: &Polynomial) -> (Polynomial, usize) {
+
+	if cmp::min(left.len(), right.len()) < MULTIPLY_CUTOFF {
+
+		return left.multiplySchoolbook(right);
+
+	} else {
+
+		return left.multiplyFFT(right);
+
+	}
+
+}
+
+///Calculates the first len coefficients of the power series 1/f with Newton's
+///iteration, g = g(2 - fg), which doubles the number of correct terms each time.
+///f[0] must not be zero. Returns the coefficients and the multiplication count.
+fn inverseSeries(f: &[Complex64], len: usize) -> (Polynomial, usize) {
+
+	let mut count: usize = 1;
+	let mut inverse = Polynomial::from(vec![Complex64::new(1.0, 0.0) / f[0]]);
+
+	while inverse.len() < len {
+
+		let have = cmp::min(inverse.len() * 2, len);
+		let truncated: Polynomial = f.iter().take(have).cloned().collect();
+
+		let (mut correction, fgCount) = multiplyFast(&truncated, &inverse);
+		correction.setLength(have);
+
+		for coeff in correction.iter_mut() {
+
+			*coeff = -*coeff;
+
+		}
+
+		correction[0] = correction[0] + 2.0;
+
+		let (mut next, nextCount) = multiplyFast(&inverse, &correction);
+		next.setLength(have);
+
+		count += fgCount + nextCount;
+		inverse = next;
+
+	}
+
+	return (inverse, count);
+
+}
+
+///Calculates the remainder of a divided by b. The quotient is found from the
+///reversed polynomials, since rev(a) / rev(b) is a power series whose first terms
+///are rev(quotient). b's leading coefficient must not be zero. Returns the
+///remainder, which is one shorter than b, and the multiplication count.
+fn remainderFast(a: &Polynomial, b: &Polynomial) -> (Polynomial, usize) {
+
+	if a.len() < b.len() {
+
+		return (a.clone(), 0);
+
+	}
+
+	let quotientLen = a.len() - b.len() + 1;
+
+	let reversedA: Polynomial = a.iter().rev().take(quotientLen).cloned().collect();
+	let reversedB: Polynomial = b.iter().rev().take(quotientLen).cloned().collect();
+
+	let (inverse, inverseCount) = inverseSeries(&reversedB, quotientLen);
+	let (mut quotient, quotientCount) = multiplyFast(&reversedA, &inverse);
+
+	quotient.setLength(quotientLen);
+	quotient.reverse();
+
+	//only the bottom terms of b * quotient are needed, the rest cancel with a
+	let (product, productCount) = multiplyFast(b, &quotient);
+	let remainder = (0..b.len() - 1).map(|i| a[i] - product[i]).collect();
+
+	return (remainder, inverseCount + quotientCount + productCount);
+
+}
+
+///Builds the subproduct tree over the points. tree[0] holds (x - point) for each
+///point, and each level up holds the products of pairs from the level below, so the
+///last level is the single product of every (x - point). An odd one out at the end
+///of a level is carried up as it is. Returns the tree and the multiplication count.
+fn subproductTree(points: &[Complex64]) -> (Vec<Vec<Polynomial>>, usize) {
+
+	let mut count: usize = 0;
+	let mut tree = vec![points.iter().map(|&point| Polynomial::from(vec![-point, Complex64::new(1.0, 0.0)])).collect::<Vec<Polynomial>>()];
+
+	while tree[tree.len() - 1].len() > 1 {
+
+		let mut level = Vec::new();
+
+		for pair in tree[tree.len() - 1].chunks(2) {
+
+			if pair.len() == 2 {
+
+				let (product, productCount) = multiplyFast(&pair[0], &pair[1]);
+				level.push(product);
+				count += productCount;
+
+			} else {
+
+				level.push(pair[0].clone());
+
+			}
+
+		}
+
+		tree.push(level);
+
+	}
+
+	return (tree, count);
+
+}
+
+///The most a single rounding can change a double by, relative to its size.
+const UNIT_ROUNDOFF: f64 = f64::EPSILON / 2.0;
+
+///The most a complex multiplication can be off by, relative to the product's size,
+///which is sqrt(5) units (Brent, Percival and Zimmermann).
+const MULTIPLY_ERROR: f64 = 2.23606797749979 * UNIT_ROUNDOFF;
+
+///The most a multiplication by a twiddle from rootOfUnity can be off by. Inside the
+///FFT theta is below pi and gets rounded three times on the way into sin_cos, which
+///can then be off by a unit in each part.
+const TWIDDLE_MULTIPLY_ERROR: f64 = MULTIPLY_ERROR + (3.0 * PI + SQRT_2) * UNIT_ROUNDOFF;
+
+///How far off x.powf(i) can be for each unit of i. powf works in polar form, so the
+///rounding error in the angle gets multiplied by i, which comes to about pi units.
+const POWER_ERROR: f64 = 4.0 * UNIT_ROUNDOFF;
+
+///Runs the same transform as fftInPlace and tracks a running bound on the rounding
+///error of every value through each butterfly. bounds should start out at zero.
+///The length must be a power of two. Returns the number of multiplications done.
+fn fftInPlaceWithBound(values: &mut [Complex64], bounds: &mut [f64]) -> usize {
+
+	let mut count: usize = 0;
+	let n = values.len();
+
+	if n <= 1 {
+
+		return count;
+
+	}
+
+	bitReversePermute(values);
+
+	let mut len = 2;
+
+	while len <= n {
+
+		let half = len / 2;
+
+		for k in 0..half {
+
+			let root = rootOfUnity(len as i32, k as i32);
+
+			for start in (0..n).step_by(len) {
+
+				let right = root * values[start + k + half];
+				count += 1;
+
+				let left = values[start + k];
+
+				values[start + k] = left + right;
+				values[start + k + half] = left - right;
+
+				//both outputs carry both inputs' errors, plus the multiply's and their own
+				let carried = bounds[start + k] + bounds[start + k + half] + TWIDDLE_MULTIPLY_ERROR * right.norm();
+
+				bounds[start + k] = carried + UNIT_ROUNDOFF * values[start + k].norm();
+				bounds[start + k + half] = carried + UNIT_ROUNDOFF * values[start + k + half].norm();
+
+			}
+
+		}
+
+		len *= 2;
+
+	}
+
+	return count;
+
+}
+
+///Runs the same steps as realFFT, with evaluateAtFFTWithBound doing the half length
+///transform, and carries its error bounds through pulling the two halves apart.
+///Returns the values, the bounds and the number of multiplications done.
+fn realFFTWithBound(coeffs: &[Complex64]) -> (Vec<Complex64>, Vec<f64>, usize) {
+
+	let n = coeffs.len();
+	let half = n / 2;
+
+	let packed: Polynomial = (0..half).map(|j| Complex64::new(coeffs[2 * j].re, coeffs[2 * j + 1].re)).collect();
+
+	let (packedValues, packedBounds, mut count) = packed.evaluateAtFFTWithBound();
+	let mut values = vec![Complex64::new(0.0, 0.0); n];
+	let mut bounds = vec![0.0; n];
+
+	for k in 0..half {
+
+		let z = packedValues[k];
+		let mirror = packedValues[(half - k) % half].conj();
+
+		let even = (z + mirror) * 0.5;
+		let odd = timesI(mirror - z) * 0.5;
+
+		let right = rootOfUnity(n as i32, k as i32) * odd;
+		count += 1;
+
+		values[k] = even + right;
+		values[k + half] = even - right;
+
+		//halving is exact, so even and odd only add their own rounding to half of what
+		//came in, and odd's goes through the twiddle's multiply
+		let carried = (packedBounds[k] + packedBounds[(half - k) % half]) * 0.5;
+		let evenBound = carried + UNIT_ROUNDOFF * even.norm();
+		let rightBound = carried + UNIT_ROUNDOFF * odd.norm() + TWIDDLE_MULTIPLY_ERROR * right.norm();
+
+		bounds[k] = evenBound + rightBound + UNIT_ROUNDOFF * values[k].norm();
+		bounds[k + half] = evenBound + rightBound + UNIT_ROUNDOFF * values[k + half].norm();
+
+	}
+
+	return (values, bounds, count);
+
+}
+
+///Adds two doubles, also returning the rounding error, so a + b = sum + error exactly.
+pub fn twoSum(a: f64, b: f64) -> (f64, f64) {
+
+	let sum = a + b;
+	let aPart = sum - b;
+	let bPart = sum - aPart;
+
+	return (sum, (a - aPart) + (b - bPart));
+
+}
+
+///Multiplies two doubles, also returning the rounding error, so a * b = product + error
+///exactly. The fused multiply-add works out the error without rounding it.
+pub fn twoProduct(a: f64, b: f64) -> (f64, f64) {
+
+	let product = a * b;
+
+	return (product, a.mul_add(b, -product));
+
+}
+
+///Adds two complex numbers so that a + b = sum + error exactly.
+fn twoSumComplex(a: Complex64, b: Complex64) -> (Complex64, Complex64) {
+
+	let (re, reError) = twoSum(a.re, b.re);
+	let (im, imError) = twoSum(a.im, b.im);
+
+	return (Complex64::new(re, im), Complex64::new(reError, imError));
+
+}
+
+///Multiplies two complex numbers so that a * b = product + productError + sumError
+///exactly. productError holds what the four real products lost, and sumError what
+///adding them back together lost.
+fn twoProductComplex(a: Complex64, b: Complex64) -> (Complex64, Complex64, Complex64) {
+
+	let (reRe, reReError) = twoProduct(a.re, b.re);
+	let (imIm, imImError) = twoProduct(a.im, b.im);
+	let (reIm, reImError) = twoProduct(a.re, b.im);
+	let (imRe, imReError) = twoProduct(a.im, b.re);
+
+	let (re, reError) = twoSum(reRe, -imIm);
+	let (im, imError) = twoSum(reIm, imRe);
+
+	let product = Complex64::new(re, im);
+	let productError = Complex64::new(reReError - imImError, reImError + imReError);
+	let sumError = Complex64::new(reError, imError);
+
+	return (product, productError, sumError);
+
+}
+
+///Composes the coefficients with q by splitting them in half at h, a power of two,
+///so p(q) = low(q) + q^h * high(q), where powers[k] is q^(2^k). Returns the
+///composition and the multiplication count.
+fn composeRecursive(coeffs: &[Complex64], powers: &[Polynomial]) -> (Polynomial, usize) {
+
+	if coeffs.len() <= 1 {
+
+		return (coeffs.iter().cloned().collect(), 0);
+
+	}
+
+	let h = coeffs.len().next_power_of_two() / 2;
+
+	let (low, lowCount) = composeRecursive(&coeffs[..h], powers);
+	let (high, highCount) = composeRecursive(&coeffs[h..], powers);
+	let (shifted, shiftedCount) = multiplyFast(&powers[h.trailing_zeros() as usize], &high);
+
+	return (&low + &shifted, lowCount + highCount + shiftedCount);
+
+}
+
+///Cuts off the top coefficients whose size is at most threshold, so a leading
+///coefficient that is only rounding error is treated as zero.
+fn trimLeading(coeffs: &[Complex64], threshold: f64) -> Polynomial {
+
+	let len = coeffs.iter().rposition(|coeff| coeff.norm() > threshold).map_or(0, |top| top + 1);
+
+	return coeffs[..len].iter().cloned().collect();
+
+}
+
+///Finds the size of the largest coefficient, which sets the scale for what counts
+///as zero next to it.
+fn largestCoefficient(coeffs: &[Complex64]) -> f64 {
+
+	return coeffs.iter().fold(0.0, |largest, coeff| largest.max(coeff.norm()));
+
+}
+
+///Generates a pretty looking string of the terms, each as (a + bi)(x^k). Lists of
+///values print the same way as coefficients.
+pub fn printTerms(terms: &[Complex64]) -> String {
+
+	let mut string = String::new();
+
+	for term in 0..terms.len() {
+
+		let real = terms[term].re;
+		let imag = terms[term].im;
+		let sign;
+
+		if imag < 0.0 {
+
+			sign = "-";
+
+		} else {
+
+			sign = "+";
+
+		}
+
+		string.push_str(&format!("({:.5} {} {:.5}i)(x^{})", real, sign, imag.abs(), term));
+
+		if term < terms.len() - 1 {
+
+			string.push_str(" + ");
+
+		}
+
+	}
+
+	return string;
+
+}
+
+///This trait defines the evaluators that only need a Scalar's arithmetic, so they
+///run the same way on polynomials of any scalar type.
+pub trait Evaluate<T: Scalar> {
+
+	///Evaluates the polynomial at the given value using the naive method
+	fn evaluateAtNaive(&self, x: T) -> (T, usize);
+
+	///Evaluates using Horner's method
+	fn evaluateAtHorner(&self, x: T) -> (T, usize);
+
+	///Evaluates using the improved naive method
+	fn evaluateAtNaiveImproved(&self, x: T) -> (T, usize);
+
+	///Evaluates the polynomial at the n roots of unity with the radix-2 FFT. This is the
+	///FFT every scalar type gets, as long as it has roots of unity and the length is a
+	///power of two. The mixed-radix, Bluestein and real coefficient transforms behind
+	///evaluateAtFFT only run on Complex64. Returns the values and the multiplication
+	///count, or an error message if the length is not a power of two.
+	fn evaluateAtFFTRadix2(&self) -> Result<(Vec<T>, usize), String> where T: RootOfUnity;
+
+}
+
+//The naive, Horner's and improved naive methods below are shared by the plain
+//evaluators and the ones with error bounds. After each step they call step with the
+//step's index, the product it just rounded and the running sum.
+
+///Evaluates the polynomial at x the naive way. Returns the value and the multiplication count.
+fn naiveSteps<T: Scalar, F: FnMut(usize, &T, &T)>(coeffs: &[T], x: T, mut step: F) -> (T, usize) {
+
+	let mut count: usize = 0;
+	let mut sum = T::zero();
+
+	for (i, coeff) in coeffs.iter().enumerate() {
+
+		let term = coeff.clone() * x.clone().powi(i);
+		sum = sum + term.clone();
+		count += 1 + i;
+
+		step(i, &term, &sum);
+
+	}
+
+	return (sum, count);
+
+}
+
+///Evaluates the polynomial at x with Horner's method. Returns the value and the multiplication count.
+fn hornerSteps<T: Scalar, F: FnMut(usize, &T, &T)>(coeffs: &[T], x: T, mut step: F) -> (T, usize) {
+
+	let mut count: usize = 0;
+	let mut sum = T::zero();
+
+	for (i, coeff) in coeffs.iter().rev().enumerate() {
+
+		let product = sum * x.clone();
+		sum = product.clone() + coeff.clone();
+		count += 1;
+
+		step(i, &product, &sum);
+
+	}
+
+	return (sum, count);
+
+}
+
+///Evaluates the polynomial at x with the improved naive method.
+///Returns the value and the multiplication count.
+fn naiveImprovedSteps<T: Scalar, F: FnMut(usize, &T, &T)>(coeffs: &[T], x: T, mut step: F) -> (T, usize) {
+
+	let mut count: usize = 0;
+	let mut sum = T::zero();
+	let mut xPower = T::one();
+
+	for (i, coeff) in coeffs.iter().enumerate() {
+
+		let term = coeff.clone() * xPower.clone();
+		sum = sum + term.clone();
+		xPower = xPower * x.clone();
+		count += 2;
+
+		step(i, &term, &sum);
+
+	}
+
+	return (sum, count);
+
+}
+
+impl<T: Scalar> Evaluate<T> for Polynomial<T> {
+
+	fn evaluateAtNaive(&self, x: T) -> (T, usize)  {
+
+		return naiveSteps(self, x, |_, _, _| {});
+
+	}
+
+	fn evaluateAtHorner(&self, x: T) -> (T, usize)  {
+
+		return hornerSteps(self, x, |_, _, _| {});
+
+	}
+
+	fn evaluateAtNaiveImproved(&self, x: T) -> (T, usize) {
+
+		return naiveImprovedSteps(self, x, |_, _, _| {});
+
+	}
+
+	fn evaluateAtFFTRadix2(&self) -> Result<(Vec<T>, usize), String> where T: RootOfUnity {
+
+		//the transform is done in place, so start from a copy of the coefficients
+		let mut values = self.to_vec();
+		let count = fftInPlace(&mut values)?;
+
+		return Ok((values, count));
+
+	}
+
+}
+
+///This trait defines what we can do with a polynomial
+pub trait Poly {
+
+	///Reads the polynomial form a file.
+	///Returns the read polynomial on success, or an error message on failure.
+	fn readFromFile(filename: &String) -> Result<Polynomial, String>;
+
+	///Generates a random polynomial with the given degree withing the given bounds
+	fn random(degree: i32, bounds: f64) -> Result<Polynomial, String>;
+
+	///Generates a pretty looking string to print to the consome.
+	fn print(&self) -> String;
+
+	///Writes the polynomial to a file.
+	///Returns Ok(()) on success, or a nerror message on failure.
+	fn writeToFile(&self, filename: &String) -> Result<(), String>;
+
+	///Evaluates p(x) and its first k derivatives in one Horner style pass, by running
+	///Horner's method on each partial result as it comes out. Returns
+	///[p(x), p'(x), ..., p^(k)(x)] and the multiplication count.
+	fn evaluateDerivativesAtHorner(&self, x: Complex64, k: usize) -> (Vec<Complex64>, usize);
+
+	///Finds all of the complex roots with the Aberth-Ehrlich method. Every root is
+	///updated at once with a Newton step that also pushes it away from all the others.
+	///It stops once every step is within tolerance, relative to the root's size.
+	///Returns the roots, the residual |p(root)| of each from Horner's method and the
+	///number of iterations taken, or an error message if it did not converge.
+	fn rootsAberth(&self, tolerance: f64, maxIterations: usize) -> Result<(Vec<Complex64>, Vec<f64>, usize), String>;
+
+	///Evaluates using the naive method, along with a running bound on the rounding error.
+	///Returns the value, the bound and the multiplication count.
+	fn evaluateAtNaiveWithBound(&self, x: Complex64) -> (Complex64, f64, usize);
+
+	///Evaluates using Horner's method, along with the standard running bound on the
+	///rounding error, built up from |x| and the size of each partial sum.
+	///Returns the value, the bound and the multiplication count.
+	fn evaluateAtHornerWithBound(&self, x: Complex64) -> (Complex64, f64, usize);
+
+	///Evaluates using the improved naive method, along with a running bound on the
+	///rounding error. Returns the value, the bound and the multiplication count.
+	fn evaluateAtNaiveImprovedWithBound(&self, x: Complex64) -> (Complex64, f64, usize);
+
+	///Evaluates at the n roots of unity with the FFT, along with an estimate of the
+	///rounding error in each value. The values are the same ones evaluateAtFFT gives,
+	///real coefficient shortcut included. Power of two lengths track a running bound
+	///through every butterfly. Other lengths get the same norm based estimate for every value.
+	///Returns the values, the error estimates and the multiplication count.
+	fn evaluateAtFFTWithBound(&self) -> (Vec<Complex64>, Vec<f64>, usize);
+
+	///Evaluates using Horner's method, but keeps track of every rounding error along the
+	///way and runs them through Horner's method too, adding them back in at the end.
+	///The result is about as accurate as Horner's method in twice the precision.
+	///Counts one multiplication for each of the two Horner steps per coefficient.
+	fn evaluateAtHornerCompensated(&self, x: Complex64) -> (Complex64, usize);
+
+	///Evaluates using Estrin's scheme, which pairs up terms and combines the pairs with
+	///x^2, x^4 and so on. It does about as many multiplies as Horner's method, but the
+	///ones on each level don't depend on each other.
+	fn evaluateAtEstrin(&self, x: Complex64) -> (Complex64, usize);
+
+	///Evaluates at the n roots of unity using the Fast Fourier Transform.
+	///Lengths made only of the factors 2, 3, 5 and 7 use a mixed-radix transform,
+	///any other length goes through Bluestein's algorithm. Even length polynomials
+	///with only real coefficients get a half length transform instead.
+	///Returns the values and the multiplication count of the transform really done,
+	///so real coefficients count the half length transform plus one twiddle for each
+	///pair of values, less than evaluateAtFFTComplex counts for the same polynomial.
+	///Four real coefficients take 3 multiplies instead of 4.
+	fn evaluateAtFFT(&self) -> (Vec<Complex64>, usize);
+
+	///The same as evaluateAtFFT, but never takes the real coefficient shortcut.
+	fn evaluateAtFFTComplex(&self) -> (Vec<Complex64>, usize);
+
+	///The same as evaluateAtFFT, but power of two transforms of at least
+	///PARALLEL_CUTOFF points are split across up to the given number of threads.
+	///The values are bit for bit the same as evaluateAtFFT's.
+	fn evaluateAtFFTParallel(&self, threads: usize) -> (Vec<Complex64>, usize);
+
+	///How many threads evaluateAtFFTParallel would really use for this polynomial.
+	fn parallelFFTThreads(&self, threads: usize) -> usize;
+
+	///Evaluates at only the chosen n-th roots of unity, w^k for each k in bins, using
+	///Goertzel's algorithm. Returns the values in the same order and the multiplication count.
+	fn evaluateAtGoertzel(&self, bins: &[usize]) -> (Vec<Complex64>, usize);
+
+	///Evaluates at any list of points at once using a subproduct tree. The polynomial
+	///is reduced mod the product of (x - point) over halves of the points, then quarters
+	///and so on, until what's left mod (x - point) is the value there.
+	///Like any subproduct tree in floating point, it loses accuracy quickly as the points
+	///move out towards the unit circle and as their number grows. A few hundred points
+	///well inside the unit circle are fine, past that check it against evaluateAtHorner.
+	///Returns the values in the same order as the points and the multiplication count.
+	fn evaluateAtPoints(&self, points: &[Complex64]) -> (Vec<Complex64>, usize);
+
+	///Goes the other way from evaluateAtFFT: takes the values at the n roots of unity
+	///and gives back the polynomial's coefficients, along with the multiplication count.
+	fn interpolateFFT(values: &[Complex64]) -> (Polynomial, usize);
+
+	///Multiplies two polynomials the schoolbook way, every term by every term.
+	///Returns the product and the multiplication count.
+	fn multiplySchoolbook(&self, other: &Polynomial) -> (Polynomial, usize);
+
+	///Multiplies two polynomials by evaluating both with the FFT, multiplying the values
+	///and interpolating the product. Returns the product and the multiplication count.
+	fn multiplyFFT(&self, other: &Polynomial) -> (Polynomial, usize);
+
+	///Divides by the divisor with long division. The divisor's top coefficients are
+	///treated as zero if they are within tolerance of zero, relative to its largest
+	///coefficient. Returns the quotient, the remainder, which is one shorter than the
+	///divisor, and the multiplication count, or an error message if the divisor is zero.
+	fn divide(&self, divisor: &Polynomial, tolerance: f64) -> Result<(Polynomial, Polynomial, usize), String>;
+
+	///Finds the monic greatest common divisor with Euclid's algorithm. A remainder counts
+	///as zero once all of its coefficients are within tolerance of zero, relative to
+	///the largest coefficient of what was divided. Returns the GCD and the
+	///multiplication count, or an error message if both polynomials are zero.
+	fn gcd(&self, other: &Polynomial, tolerance: f64) -> Result<(Polynomial, usize), String>;
+
+	///Divides by (x - a) with synthetic division, which is Horner's method keeping
+	///the partial sums. Returns the quotient, the remainder p(a) and the
+	///multiplication count, which is the same as evaluateAtHorner's.
+	fn divideSynthetic(&self, a: Complex64) -> (Polynomial, Complex64, usize);
+
+	///Calculates the coefficients of p(q(x)) with Horner's method, where each step
+	///multiplies by q the schoolbook way. Returns the composition and the
+	///multiplication count.
+	fn composeHorner(&self, inner: &Polynomial) -> (Polynomial, usize);
+
+	///Calculates the coefficients of p(q(x)) by divide and conquer, splitting p in half
+	///and multiplying the halves back together with the powers q^(2^k), using the FFT
+	///once they are long enough. Returns the composition and the multiplication count.
+	fn composeFFT(&self, inner: &Polynomial) -> (Polynomial, usize);
+
+	///Calculates the coefficients of p'(x). Returns the derivative and the
+	///multiplication count.
+	fn derivative(&self) -> (Polynomial, usize);
+
+	///Calculates the coefficients of the antiderivative whose value at zero is constant.
+	///Returns the antiderivative and the multiplication count.
+	fn integral(&self, constant: Complex64) -> (Polynomial, usize);
+
+	///Calculates the coefficients of p(x + a) by synthetic division by (x - a) over and
+	///over, which takes O(n^2) multiplies. Returns the shifted polynomial and the
+	///multiplication count.
+	fn taylorShiftHorner(&self, a: Complex64) -> (Polynomial, usize);
+
+	///Calculates the coefficients of p(x + a) with one FFT convolution of a_i * i! and
+	///a^j / j!, which takes O(n log n) multiplies. The factorials still spread the terms
+	///apart, so while shifts of about one or more come out accurate relative to the
+	///largest coefficient, smaller shifts of long polynomials lose digits, around half
+	///of them by 100 terms. Returns the shifted polynomial and the multiplication count.
+	fn taylorShiftFFT(&self, a: Complex64) -> (Polynomial, usize);
+
+}
+
+impl Poly for Polynomial {
+
+	fn readFromFile(filename: &String) -> Result<Polynomial, String> {
+
+		let file = File::open(filename.trim());
+	    if file.is_err() { return Err("Failed to open file.".to_string()); }
+
+	    let mut all_text = String::new();
+	    let _ = file.unwrap().read_to_string(&mut all_text);
+
+	    let polyresults = polyfile(all_text.as_bytes());
+	    let degrees: i64;
+	    let poly: Polynomial;
+	    match polyresults {
+	        Done(extra, (deg, pol)) => {
+	            //convert from raw bytes to a utf8 string slice
+	            let extra_str = str::from_utf8(extra).unwrap();
+	            //there should be nothing left in the file
+	            if extra_str.trim().len() > 0 {
+	                return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(extra)));
+	            }
+	            degrees = deg;
+	            poly = pol;
+	        },
+	        Error(errco) => {
+	            match errco {
+	                Position(_, pos) => return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(pos))),
+	                _ => return Err("Parsing failed, invalid format.".to_string())
+	            }
+	        }
+	        Incomplete(_) => return Err("Parsing failed, invalid format.".to_string()),
+	    }
+
+	    if degrees < 0 {
+	        return Err("Polynomial degree cannot be negative.".to_string());
+	    } else if degrees != poly.len() as i64 {
+	        println!("{:?} != {:?}\n{:?}", degrees, poly.len(), poly);
+	        return Err(format!("Incorrect number of coefficients, {} instead of {}.", poly.len(), degrees));
+	    }
+
+	    return Ok(poly);
+
+	}
+
+	fn random(degree: i32, bounds: f64) -> Result<Polynomial, String> {
+
+		if degree < 0 {
+
+			return Err("You can't have a polynomial of degree less than zero.".to_string());
+
+		} else if bounds <= 0.0 {
+
+			return Err("Range is negative or zero.".to_string());
+
+		}
+
+		let mut poly = Vec::with_capacity(degree as usize);
+
+		let range = Range::new(-1.0 * bounds, bounds);
+		let mut rng = thread_rng();
+
+		for _ in 0..degree {
+
+			let real: f64 = range.ind_sample(&mut rng);
+			let imag: f64 = range.ind_sample(&mut rng);
+
+			poly.push(Complex64::new(real, imag));
+
+		}
+
+		return Ok(Polynomial::from(poly));
+
+	}
+
+	fn print(&self) -> String {
+
+		return printTerms(self);
+
+	}
+
+	fn writeToFile(&self, filename: &String) -> Result<(), String> {
+
+		//attempt to open the file
+		let file = File::create(filename.trim());
+		if file.is_err() { return Err("Failed to create file.".to_string()); }
+
+		let mut outFile = BufWriter::new(file.unwrap());
+
+		//first, we write the degree and a newline
+		let result = outFile.write(format!("{}\n", self.len()).as_bytes());
+
+		if let Err(_) = result {
+
+			return Err("Could not write to file.".to_string());
+
+		}
+
+		//then, for each element, write "real,imag\n"
+		for item in self {
+
+			let result = outFile.write(format!("{},{}\n", item.re, item.im).as_bytes());
+
+			if let Err(_) = result {
+
+				return Err("Could not write to file.".to_string());
+
+			}
+
+		}
+
+		return Ok(());
+
+	}
+
+	fn evaluateDerivativesAtHorner(&self, x: Complex64, k: usize) -> (Vec<Complex64>, usize) {
+
+		let mut count: usize = 0;
+		let mut sums = vec![Complex64::new(0.0, 0.0); k + 1];
+
+		for (done, coeff) in self.iter().rev().enumerate() {
+
+			//sums[j] only picks anything up once j coefficients have gone through,
+			//and it has to be updated before the sum it feeds off of
+			for j in (1..cmp::min(k, done) + 1).rev() {
+
+				sums[j] = (sums[j] * x) + sums[j - 1];
+				count += 1;
+
+			}
+
+			sums[0] = (sums[0] * x) + coeff;
+			count += 1;
+
+		}
+
+		//sums[j] is the j-th Taylor coefficient, p^(j)(x) / j!
+		let mut factorial = 1.0;
+
+		for (j, sum) in sums.iter_mut().enumerate().skip(2) {
+
+			factorial *= j as f64;
+			*sum = *sum * factorial;
+			count += 1;
+
+		}
+
+		return (sums, count);
+
+	}
+
+	fn rootsAberth(&self, tolerance: f64, maxIterations: usize) -> Result<(Vec<Complex64>, Vec<f64>, usize), String> {
+
+		//zero terms on top don't change the roots, they would just divide by zero
+		let len = self.iter().rposition(|coeff| coeff.norm() != 0.0).map_or(0, |top| top + 1);
+		let poly: Polynomial = self[..len].iter().cloned().collect();
+
+		if poly.is_empty() {
+
+			return Err("The zero polynomial is zero everywhere.".to_string());
+
+		}
+
+		let degree = poly.len() - 1;
+		let leading = poly[degree].norm();
+
+		//start evenly spaced around a circle that holds all the roots, turned a bit so
+		//no guess lands on the real axis by symmetry. Twice the largest |a_i/a_n|^(1/(n-i))
+		//is at least Fujiwara's bound, so nothing lies outside it
+		let mut radius: f64 = 0.0;
+
+		for (i, coeff) in poly.iter().enumerate().take(degree) {
+
+			radius = radius.max((coeff.norm() / leading).powf(1.0 / (degree - i) as f64));
+
+		}
+
+		radius *= 2.0;
+
+		let mut roots: Vec<Complex64> = (0..degree).map(|k| {
+
+			let theta = (2.0 * PI * k as f64) / degree as f64 + 0.4;
+			return Complex64::from_polar(&radius.max(1e-3), &theta);
+
+		}).collect();
+
+		let mut iterations = 0;
+		let mut converged = false;
+
+		while !converged && iterations < maxIterations {
+
+			converged = true;
+			iterations += 1;
+
+			for k in 0..degree {
+
+				let (values, _) = poly.evaluateDerivativesAtHorner(roots[k], 1);
+
+				if values[0].norm() == 0.0 {
+
+					continue;
+
+				}
+
+				//the Newton step, then the correction for every other root
+				let newton = values[0] / values[1];
+				let mut repulsion = Complex64::new(0.0, 0.0);
+
+				for j in 0..degree {
+
+					if j != k {
+
+						repulsion = repulsion + Complex64::new(1.0, 0.0) / (roots[k] - roots[j]);
+
+					}
+
+				}
+
+				let step = newton / (Complex64::new(1.0, 0.0) - newton * repulsion);
+				roots[k] = roots[k] - step;
+
+				//a NaN step compares false, so it never counts as converged
+				let withinTolerance = step.norm() <= tolerance * roots[k].norm().max(1.0);
+
+				if !withinTolerance {
+
+					converged = false;
+
+				}
+
+			}
+
+		}
+
+		if !converged {
+
+			return Err(format!("Did not converge within {} iterations.", maxIterations));
+
+		}
+
+		let residuals = roots.iter().map(|&root| poly.evaluateAtHorner(root).0.norm()).collect();
+
+		return Ok((roots, residuals, iterations));
+
+	}
+
+	fn evaluateAtNaiveWithBound(&self, x: Complex64) -> (Complex64, f64, usize) {
+
+		let mut bound = 0.0;
+
+		let (sum, count) = naiveSteps(self, x, |i, term, sum| {
+
+			bound += (MULTIPLY_ERROR + i as f64 * POWER_ERROR) * term.norm() + UNIT_ROUNDOFF * sum.norm();
+
+		});
+
+		return (sum, bound, count);
+
+	}
+
+	fn evaluateAtHornerWithBound(&self, x: Complex64) -> (Complex64, f64, usize) {
+
+		let mut bound = 0.0;
+		let xNorm = x.norm();
+
+		let (sum, count) = hornerSteps(self, x, |_, product, sum| {
+
+			//the error so far gets multiplied by x, then the multiply and add each round
+			bound = (xNorm * bound) + (MULTIPLY_ERROR * product.norm()) + (UNIT_ROUNDOFF * sum.norm());
+
+		});
+
+		return (sum, bound, count);
+
+	}
+
+	fn evaluateAtNaiveImprovedWithBound(&self, x: Complex64) -> (Complex64, f64, usize) {
+
+		let mut bound = 0.0;
+
+		let (sum, count) = naiveImprovedSteps(self, x, |i, term, sum| {
+
+			//x^i took i multiplies, each rounded
+			bound += (i + 1) as f64 * MULTIPLY_ERROR * term.norm() + UNIT_ROUNDOFF * sum.norm();
+
+		});
+
+		return (sum, bound, count);
+
+	}
+
+	fn evaluateAtFFTWithBound(&self) -> (Vec<Complex64>, Vec<f64>, usize) {
+
+		let n = self.len();
+
+		//follow evaluateAtFFT into the real coefficient shortcut, so the bounds are for
+		//the values it really gives
+		if n > 1 && n % 2 == 0 && self.iter().all(|coeff| coeff.im == 0.0) {
+
+			return realFFTWithBound(self);
+
+		}
+
+		if n <= 1 || n.is_power_of_two() {
+
+			let mut values = self.to_vec();
+			let mut bounds = vec![0.0; n];
+			let count = fftInPlaceWithBound(&mut values, &mut bounds);
+
+			return (values, bounds, count);
+
+		}
+
+		//the usual estimate for an FFT's error is about log2(n) roundings per value,
+		//relative to the 2-norm of the coefficients. Bluestein does three transforms
+		//of about twice the length, so give it the benefit of the doubt on neither.
+		let (values, count) = self.evaluateAtFFTComplex();
+		let norm = self.iter().map(|coeff| coeff.norm_sqr()).sum::<f64>().sqrt();
+		let passes = (n as f64).log2().ceil();
+		let passes = if radixFactors(n).is_some() { passes } else { 3.0 * (passes + 1.0) };
+		let estimate = passes * (TWIDDLE_MULTIPLY_ERROR + UNIT_ROUNDOFF) * norm;
+
+		return (values, vec![estimate; n], count);
+
+	}
+
+	fn evaluateAtHornerCompensated(&self, x: Complex64) -> (Complex64, usize) {
+
+		let mut count: usize = 0;
+		let mut sum = Complex64::new(0.0, 0.0);
+		let mut correction = Complex64::new(0.0, 0.0);
+
+		for coeff in self.iter().rev() {
+
+			let (product, productError, productSumError) = twoProductComplex(sum, x);
+			let (next, sumError) = twoSumComplex(product, *coeff);
+
+			sum = next;
+			correction = (correction * x) + (productError + productSumError + sumError);
+			count += 2;
+
+		}
+
+		return (sum + correction, count);
+
+	}
+
+	fn evaluateAtEstrin(&self, x: Complex64) -> (Complex64, usize) {
+
+		let mut count: usize = 0;
+
+		if self.is_empty() {
+
+			return (Complex64::new(0.0, 0.0), count);
+
+		}
+
+		//x^(2^level) for each level reached so far, squared up as they're needed
+		let mut powers = [x; 64];
+		let mut levels = 1;
+
+		//the values of the finished blocks of 2^level terms, leftmost first. Like the
+		//digits of a binary counter, adding a term merges every block of the same size
+		//as the one it makes, so there's never more than one block of each size
+		let mut blocks = [(Complex64::new(0.0, 0.0), 0); 64];
+		let mut depth = 0;
+
+		for &coeff in self.iter() {
+
+			let mut value = coeff;
+			let mut level = 0;
+
+			while depth > 0 && blocks[depth - 1].1 == level {
+
+				if levels == level {
+
+					powers[levels] = powers[levels - 1] * powers[levels - 1];
+					levels += 1;
+					count += 1;
+
+				}
+
+				value = blocks[depth - 1].0 + (value * powers[level]);
+				count += 1;
+
+				depth -= 1;
+				level += 1;
+
+			}
+
+			blocks[depth] = (value, level);
+			depth += 1;
+
+		}
+
+		//the blocks left over get smaller going right, and go together from the right,
+		//the same as the odd ones out moving up a level
+		let mut value = blocks[depth - 1].0;
+
+		for &(block, level) in blocks[..depth - 1].iter().rev() {
+
+			if levels == level {
+
+				powers[levels] = powers[levels - 1] * powers[levels - 1];
+				levels += 1;
+				count += 1;
+
+			}
+
+			value = block + (value * powers[level]);
+			count += 1;
+
+		}
+
+		return (value, count);
+
+	}
+
+	fn evaluateAtFFT(&self) -> (Vec<Complex64>, usize) {
+
+		let n = self.len();
+
+		if n > 1 && n % 2 == 0 && self.iter().all(|coeff| coeff.im == 0.0) {
+
+			return realFFT(self, |packed| packed.evaluateAtFFT());
+
+		}
+
+		return self.evaluateAtFFTComplex();
+
+	}
+
+	fn evaluateAtFFTParallel(&self, threads: usize) -> (Vec<Complex64>, usize) {
+
+		let n = self.len();
+
+		if n > 1 && n % 2 == 0 && self.iter().all(|coeff| coeff.im == 0.0) {
+
+			return realFFT(self, |packed| packed.evaluateAtFFTParallel(threads));
+
+		}
+
+		if parallelThreadCount(n, threads) == 1 {
+
+			return self.evaluateAtFFTComplex();
+
+		}
+
+		let mut values = self.to_vec();
+		let count = fftInPlaceParallel(&mut values, threads);
+
+		return (values, count);
+
+	}
+
+	fn parallelFFTThreads(&self, threads: usize) -> usize {
+
+		let n = self.len();
+
+		//real coefficients are transformed at half the length
+		if n > 1 && n % 2 == 0 && self.iter().all(|coeff| coeff.im == 0.0) {
+
+			return parallelThreadCount(n / 2, threads);
+
+		}
+
+		return parallelThreadCount(n, threads);
+
+	}
+
+	fn evaluateAtGoertzel(&self, bins: &[usize]) -> (Vec<Complex64>, usize) {
+
+		let mut count: usize = 0;
+		let n = self.len();
+		let mut values = Vec::with_capacity(bins.len());
+
+		for &k in bins {
+
+			if n == 0 {
+
+				values.push(Complex64::new(0.0, 0.0));
+				continue;
+
+			}
+
+			//w^k and its conjugate are the roots of x^2 - 2cos(theta)x + 1, so running
+			//the coefficients through that quadratic from the top down only ever
+			//multiplies by the real number 2cos(theta)
+			let root = rootOfUnity(n as i32, (k % n) as i32);
+			let twiceCos = 2.0 * root.re;
+
+			let mut previous = Complex64::new(0.0, 0.0);
+			let mut current = Complex64::new(0.0, 0.0);
+
+			for coeff in self.iter().rev() {
+
+				let next = coeff + current * twiceCos - previous;
+				count += 1;
+
+				previous = current;
+				current = next;
+
+			}
+
+			//p(w^k) is what's left after taking off the conjugate root's share
+			values.push(current - root.conj() * previous);
+			count += 1;
+
+		}
+
+		return (values, count);
+
+	}
+
+	fn evaluateAtPoints(&self, points: &[Complex64]) -> (Vec<Complex64>, usize) {
+
+		if points.is_empty() {
+
+			return (vec![], 0);
+
+		}
+
+		let (tree, mut count) = subproductTree(points);
+
+		//walk down the tree, taking each remainder mod the two halves below it
+		let (rootRemainder, rootCount) = remainderFast(self, &tree[tree.len() - 1][0]);
+		let mut remainders = vec![rootRemainder];
+		count += rootCount;
+
+		for level in tree.iter().rev().skip(1) {
+
+			let mut next = Vec::with_capacity(level.len());
+
+			for (i, node) in level.iter().enumerate() {
+
+				let (remainder, remainderCount) = remainderFast(&remainders[i / 2], node);
+				next.push(remainder);
+				count += remainderCount;
+
+			}
+
+			remainders = next;
+
+		}
+
+		//what's left mod (x - point) is just the constant p(point)
+		let values = remainders.iter().map(|remainder| if remainder.is_empty() { Complex64::new(0.0, 0.0) } else { remainder[0] }).collect();
+
+		return (values, count);
+
+	}
+
+	fn evaluateAtFFTComplex(&self) -> (Vec<Complex64>, usize) {
+
+		let n = self.len();
+
+		if n > 1 && !n.is_power_of_two() {
+
+			//sizes made of 2, 3, 5 and 7 get their own butterflies, anything else
+			//has to go through a convolution
+			if let Some(factors) = radixFactors(n) {
+
+				let tables = MixedRadixTables::new(n);
+				let mut values = vec![Complex64::new(0.0, 0.0); n];
+				let count = mixedRadix(self, 1, &factors, &mut values, &tables);
+
+				return (values, count);
+
+			} else {
+
+				return bluestein(self);
+
+			}
+
+		}
+
+		return self.evaluateAtFFTRadix2().unwrap();
+
+	}
+
+	fn interpolateFFT(values: &[Complex64]) -> (Polynomial, usize) {
+
+		let mut coeffs = values.to_vec();
+		let count = inverseFftInPlace(&mut coeffs);
+
+		return (Polynomial::from(coeffs), count);
+
+	}
+
+	fn multiplySchoolbook(&self, other: &Polynomial) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+
+		if self.is_empty() || other.is_empty() {
+
+			return (Polynomial::new(), count);
+
+		}
+
+		let mut product = vec![Complex64::new(0.0, 0.0); self.len() + other.len() - 1];
+
+		for (i, left) in self.iter().enumerate() {
+
+			for (j, right) in other.iter().enumerate() {
+
+				product[i + j] = product[i + j] + (left * right);
+				count += 1;
+
+			}
+
+		}
+
+		return (Polynomial::from(product), count);
+
+	}
+
+	fn multiplyFFT(&self, other: &Polynomial) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+
+		if self.is_empty() || other.is_empty() {
+
+			return (Polynomial::new(), count);
+
+		}
+
+		//pad both to a power of two that can hold the whole product, so the
+		//circular convolution the FFT does never wraps around
+		let len = self.len() + other.len() - 1;
+		let n = len.next_power_of_two();
+
+		let mut left = self.clone();
+		let mut right = other.clone();
+
+		left.setLength(n);
+		right.setLength(n);
+
+		//both were padded out to the plan's length
+		let plan = FftPlan::new(n).unwrap();
+
+		count += plan.transform(&mut left).unwrap();
+		count += plan.transform(&mut right).unwrap();
+
+		for k in 0..n {
+
+			left[k] = left[k] * right[k];
+			count += 1;
+
+		}
+
+		count += plan.inverseTransform(&mut left).unwrap();
+		left.setLength(len);
+
+		return (left, count);
+
+	}
+
+	fn divide(&self, divisor: &Polynomial, tolerance: f64) -> Result<(Polynomial, Polynomial, usize), String> {
+
+		let divisor = trimLeading(divisor, tolerance * largestCoefficient(divisor));
+
+		if divisor.is_empty() {
+
+			return Err("Cannot divide by the zero polynomial.".to_string());
+
+		}
+
+		let mut count: usize = 0;
+		let m = divisor.len();
+
+		//the whole dividend is left over, padded out to the remainder's usual length
+		if self.len() < m {
+
+			let mut remainder = self.clone();
+			remainder.setLength(m - 1);
+
+			return Ok((Polynomial::new(), remainder, count));
+
+		}
+
+		let inverse = Complex64::new(1.0, 0.0) / divisor[m - 1];
+		count += 1;
+
+		let mut remainder = self.clone();
+		let mut quotient = Polynomial::from(vec![Complex64::new(0.0, 0.0); self.len() - m + 1]);
+
+		//take off a multiple of the divisor at a time, starting from the top term
+		for i in (0..quotient.len()).rev() {
+
+			quotient[i] = remainder[i + m - 1] * inverse;
+			count += 1;
+
+			//the top term cancels exactly, so it is set instead of worked out
+			remainder[i + m - 1] = Complex64::new(0.0, 0.0);
+
+			for j in 0..m - 1 {
+
+				remainder[i + j] = remainder[i + j] - (quotient[i] * divisor[j]);
+				count += 1;
+
+			}
+
+		}
+
+		remainder.setLength(m - 1);
+
+		return Ok((quotient, remainder, count));
+
+	}
+
+	fn gcd(&self, other: &Polynomial, tolerance: f64) -> Result<(Polynomial, usize), String> {
+
+		let mut count: usize = 0;
+		let mut a = trimLeading(self, tolerance * largestCoefficient(self));
+		let mut b = trimLeading(other, tolerance * largestCoefficient(other));
+
+		if a.is_empty() && b.is_empty() {
+
+			return Err("Both polynomials are zero, so there is no greatest common divisor.".to_string());
+
+		}
+
+		while !b.is_empty() {
+
+			let (_, remainder, divideCount) = a.divide(&b, tolerance)?;
+			count += divideCount;
+
+			//what is left over is only rounding error once it is tiny next to a
+			let remainder = trimLeading(&remainder, tolerance * largestCoefficient(&a));
+
+			a = b;
+			b = remainder;
+
+		}
+
+		let inverse = Complex64::new(1.0, 0.0) / a[a.len() - 1];
+		count += 1;
+
+		let monic = &a * inverse;
+		count += a.len();
+
+		return Ok((monic, count));
+
+	}
+
+	fn divideSynthetic(&self, a: Complex64) -> (Polynomial, Complex64, usize) {
+
+		let mut count: usize = 0;
+		let mut sum = Complex64::new(0.0, 0.0);
+		let mut quotient = Polynomial::from(vec![Complex64::new(0.0, 0.0); self.len().saturating_sub(1)]);
+
+		//each partial sum of Horner's method is the next coefficient of the quotient down
+		for (term, coeff) in self.iter().enumerate().rev() {
+
+			sum = (sum * a) + coeff;
+			count += 1;
+
+			if term > 0 {
+
+				quotient[term - 1] = sum;
+
+			}
+
+		}
+
+		return (quotient, sum, count);
+
+	}
+
+	fn composeHorner(&self, inner: &Polynomial) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+		let mut sum = Polynomial::new();
+
+		for coeff in self.iter().rev() {
+
+			let (product, productCount) = sum.multiplySchoolbook(inner);
+			sum = &product + &Polynomial::from(vec![*coeff]);
+			count += productCount;
+
+		}
+
+		return (sum, count);
+
+	}
+
+	fn composeFFT(&self, inner: &Polynomial) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+
+		//the largest split is half of the next power of two up
+		let mut powers = vec![inner.clone()];
+
+		while (1 << powers.len()) < self.len() {
+
+			let last = &powers[powers.len() - 1];
+			let (square, squareCount) = multiplyFast(last, last);
+
+			powers.push(square);
+			count += squareCount;
+
+		}
+
+		let (composition, composeCount) = composeRecursive(self, &powers);
+
+		return (composition, count + composeCount);
+
+	}
+
+	fn derivative(&self) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+		let mut derivative = Polynomial::from(vec![Complex64::new(0.0, 0.0); self.len().saturating_sub(1)]);
+
+		for (term, coeff) in self.iter().enumerate().skip(1) {
+
+			derivative[term - 1] = coeff * term as f64;
+			count += 1;
+
+		}
+
+		return (derivative, count);
+
+	}
+
+	fn integral(&self, constant: Complex64) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+		let mut integral = Polynomial::from(vec![constant; self.len() + 1]);
+
+		for (term, coeff) in self.iter().enumerate() {
+
+			integral[term + 1] = coeff / (term + 1) as f64;
+			count += 1;
+
+		}
+
+		return (integral, count);
+
+	}
+
+	fn taylorShiftHorner(&self, a: Complex64) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+		let mut shifted = self.clone();
+		let n = self.len();
+
+		//each pass is a synthetic division of what is left of the quotient, leaving
+		//the next coefficient of p(x + a) behind as its remainder
+		for done in 0..n {
+
+			for term in (done..n - 1).rev() {
+
+				shifted[term] = shifted[term] + (a * shifted[term + 1]);
+				count += 1;
+
+			}
+
+		}
+
+		return (shifted, count);
+
+	}
+
+	fn taylorShiftFFT(&self, a: Complex64) -> (Polynomial, usize) {
+
+		let mut count: usize = 0;
+		let n = self.len();
+
+		if n <= 1 {
+
+			return (self.clone(), count);
+
+		}
+
+		//the x^k coefficient of p(x + a) is 1 / k! times the sum of (a_i i!)(a^j / j!)
+		//over i - j = k, one convolution of the first with the second backwards. The
+		//FFT's rounding is relative to the largest term, so with x scaled by t the
+		//factorials become i! / t^i instead, which stay a lot closer together when t
+		//is about n / e
+		let t = (n as f64 / E).max(1.0);
+
+		let mut scaled = Vec::with_capacity(n);
+		let mut factorial = 1.0;
+
+		for i in 0..n {
+
+			if i > 0 { factorial *= i as f64 / t; }
+
+			scaled.push(self[i] * factorial);
+			count += 1;
+
+		}
+
+		scaled.reverse();
+
+		let mut powers = Vec::with_capacity(n);
+		let mut power = Complex64::new(1.0, 0.0);
+		let step = a * t;
+
+		for j in 0..n {
+
+			if j > 0 {
+
+				power = power * step / j as f64;
+				count += 1;
+
+			}
+
+			powers.push(power);
+
+		}
+
+		let (product, productCount) = Polynomial::from(scaled).multiplyFFT(&Polynomial::from(powers));
+		count += productCount;
+
+		//reversing the first put the sum for x^k at n - 1 - k, with k! / t^k left to undo
+		let mut shifted = Vec::with_capacity(n);
+		let mut factorial = 1.0;
+
+		for k in 0..n {
+
+			if k > 0 { factorial *= k as f64 / t; }
+
+			shifted.push(product[n - 1 - k] / factorial);
+			count += 1;
+
+		}
+
+		return (Polynomial::from(shifted), count);
+
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use num_complex::Complex32;
+	use num_complex::Complex64;
+
+	const TOLERANCE: f64 = 0.0001;
+
+	fn compare_within_tolerance(left: &[Complex64], right: &[Complex64]){
+
+		if left.len() != right.len() {
+
+			panic!("Lengths are not equal. Left: {:?}, Right: {:?}", left, right);
+
+		}
+
+		for i in 0..left.len() {
+
+			let diff = left[i] - right[i];
+
+			let diffReal = diff.re.abs();
+			let diffImag = diff.im.abs();
+
+			if diffReal > TOLERANCE {
+
+				panic!("Difference on real part {} is greater than tolerance {}. Index: {}, Left: {:?}, Right: {:?}", diffReal, TOLERANCE, i, left, right);
+
+			} else if diffImag > TOLERANCE {
+
+				panic!("Difference on imag part {} is greater than tolerance {}. Index: {}, Left: {:?}, Right: {:?}", diffImag, TOLERANCE, i, left, right);
+
+			}
+
+		}
+
+	}
+
+	#[test]
+	fn test_roots_of_unity(){
+
+		let roots = rootsOfUnity(1);
+		compare_within_tolerance(&roots, &[Complex64::new(1.0, 0.0)]);
+
+		let roots = rootsOfUnity(2);
+		compare_within_tolerance(&roots, &[Complex64::new(1.0, 0.0), Complex64::new(-1.0, 0.0)]);
+
+		let roots = rootsOfUnity(4);
+		compare_within_tolerance(&roots, &[Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0), Complex64::new(-1.0, 0.0), Complex64::new(0.0, -1.0)]);
+
+	}
+
+	#[test]
+	fn test_read_file(){
+
+		let result = Polynomial::readFromFile(&"./data/file_valid.txt".to_string()).unwrap();
+		assert_eq!(result, vec![Complex64::new(1.0,0.0), Complex64::new(2.0,3.25), Complex64::new(3.25,1.0)]);
+
+	}
+
+	#[test]
+	fn test_read_file_nonexistant(){
+
+		let result = Polynomial::readFromFile(&"./data/file_nonexistant.txt".to_string());
+		assert_eq!(result, Err("Failed to open file.".to_string()));
+
+	}
+
+	#[test]
+	fn test_read_file_zero_degree(){
+
+		let result = Polynomial::readFromFile(&"./data/file_valid_zero_degree.txt".to_string()).unwrap();
+		assert_eq!(result, Vec::new());
+
+	}
+
+	#[test]
+	fn test_read_file_invalid_has_junk_degree(){
+
+		let result = Polynomial::readFromFile(&"./data/file_invalid_has_junk_degree.txt".to_string());
+		assert_eq!(result, Err("Parsing failed. Invalid syntax \'junk\' in file.".to_string()));
+
+	}
+
+	#[test]
+	fn test_read_file_invalid_has_junk_coeffs(){
+
+		let result = Polynomial::readFromFile(&"./data/file_invalid_has_junk.txt".to_string());
+		assert_eq!(result, Err("Parsing failed. Invalid syntax \'junk\' in file.".to_string()));
+
+	}
+
+	#[test]
+	fn test_read_file_invalid_has_more_coeffs(){
+
+		let result = Polynomial::readFromFile(&"./data/file_invalid_more_coeffs.txt".to_string());
+		assert_eq!(result, Err("Incorrect number of coefficients, 4 instead of 3.".to_string()));
+
+	}
+
+	#[test]
+	fn test_random_poly(){
+
+		let result = Polynomial::random(3, 5.0).unwrap();
+		assert_eq!(result.len(), 3);
+
+	}
+
+	#[test]
+	fn test_random_poly_zero_degree(){
+
+		let result = Polynomial::random(-1, 5.0);
+		assert_eq!(result, Err("You can't have a polynomial of degree less than zero.".to_string()));
+
+	}
+
+	#[test]
+	fn test_random_poly_zero_range(){
+
+		let result = Polynomial::random(3, -5.0);
+		assert_eq!(result, Err("Range is negative or zero.".to_string()));
+
+	}
+
+	#[test]
+	fn test_evaluate_naive(){
+
+		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
+		let roots = rootsOfUnity(poly.len() as i32);
+
+		let mut attempt = Vec::with_capacity(poly.len());
+		let mut counts = 0;
+
+		for root in roots {
+
+			let (value, count) = poly.evaluateAtNaive(root);
+			attempt.push(value);
+			counts += count;
+
+		}
+
+		compare_within_tolerance(&attempt, &answer);
+		assert_eq!(counts, 40);
+
+	}
+
+	#[test]
+	fn test_evaluate_horner(){
+
+		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
+		let roots = rootsOfUnity(poly.len() as i32);
+
+		let mut attempt = Vec::with_capacity(poly.len());
+		let mut counts = 0;
+
+		for root in roots {
+
+			let (value, count) = poly.evaluateAtHorner(root);
+			attempt.push(value);
+			counts += count;
+
+		}
+
+		compare_within_tolerance(&attempt, &answer);
+		assert_eq!(counts, 16);
+
+	}
+
+	#[test]
+	fn test_evaluate_horner_compensated(){
+
+		//(x - 1)^8 right next to its root, where the terms cancel almost completely
+		let poly: Polynomial = [1.0, -8.0, 28.0, -56.0, 70.0, -56.0, 28.0, -8.0, 1.0].iter().map(|&re| Complex64::new(re, 0.0)).collect();
+		let x = Complex64::new(1.0 + 2.0f64.powi(-10), 0.0);
+		let exact = 2.0f64.powi(-80);
+
+		let (plain, _) = poly.evaluateAtHorner(x);
+		let (compensated, count) = poly.evaluateAtHornerCompensated(x);
+
+		assert!((plain.re - exact).abs() / exact > 1.0);
+		assert!((compensated.re - exact).abs() / exact < 1e-4);
+		assert_eq!(compensated.im, 0.0);
+		assert_eq!(count, 18);
+
+	}
+
+	#[test]
+	fn test_evaluate_derivatives_horner(){
+
+		//p(x) = 1 + 2x + 3x^2 + 4x^3
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let x = Complex64::new(2.0, 0.0);
+
+		let (derivatives, count) = poly.evaluateDerivativesAtHorner(x, 4);
+		let answer: Polynomial = [49.0, 62.0, 54.0, 24.0, 0.0].iter().map(|&re| Complex64::new(re, 0.0)).collect();
+
+		compare_within_tolerance(&derivatives, &answer);
+		assert_eq!(count, 13);
+
+		let (derivatives, count) = poly.evaluateDerivativesAtHorner(x, 1);
+
+		compare_within_tolerance(&derivatives, &answer[..2]);
+		assert_eq!(count, 7);
+
+	}
+
+	#[test]
+	fn test_roots_aberth(){
+
+		let expected = [Complex64::new(1.0, 0.0), Complex64::new(-2.0, 0.0), Complex64::new(0.0, 3.0), Complex64::new(0.5, -0.5), Complex64::new(-1.0, -1.0)];
+
+		//multiply out (x - root) for each root
+		let mut poly = Polynomial::from(vec![Complex64::new(1.0, 0.0)]);
+
+		for root in expected.iter() {
+
+			poly = poly.multiplySchoolbook(&Polynomial::from(vec![-root, Complex64::new(1.0, 0.0)])).0;
+
+		}
+
+		let (roots, residuals, _) = poly.rootsAberth(1e-12, 100).unwrap();
+		assert_eq!(roots.len(), expected.len());
+
+		for root in expected.iter() {
+
+			assert!(roots.iter().any(|found| (found - root).norm() < 1e-8), "{} was not found in {:?}", root, roots);
+
+		}
+
+		assert!(residuals.iter().all(|&residual| residual < 1e-8));
+
+		//the roots past the unit circle have huge terms, so check the residuals against
+		//the size of the terms instead
+		let poly = Polynomial::readFromFile(&"data/poly_0064.txt".to_string()).unwrap();
+		let (roots, residuals, _) = poly.rootsAberth(1e-12, 500).unwrap();
+
+		for (root, residual) in roots.iter().zip(residuals.iter()) {
+
+			let magnitude: f64 = poly.iter().enumerate().map(|(i, coeff)| coeff.norm() * root.norm().powi(i as i32)).sum();
+			assert!(residual / magnitude < 1e-12);
+
+		}
+
+		assert_eq!(Polynomial::from(vec![Complex64::new(0.0, 0.0)]).rootsAberth(1e-12, 100), Err("The zero polynomial is zero everywhere.".to_string()));
+
+	}
+
+	#[test]
+	fn test_evaluate_with_bound(){
+
+		for &n in &[16, 255, 1024] {
+
+			let poly = Polynomial::random(n, 10.0).unwrap();
+			let roots = rootsOfUnity(n);
+			let (fftValues, fftBounds, _) = poly.evaluateAtFFTWithBound();
+
+			//the roots the others get are rounded, which moves their values by up to
+			//|p'(x)| times the rounding, where the FFT's bound is against exact roots
+			let rootError: f64 = poly.iter().enumerate().map(|(i, coeff)| i as f64 * coeff.norm()).sum::<f64>() * (6.0 * PI + SQRT_2) * UNIT_ROUNDOFF;
+
+			for (k, root) in roots.into_iter().enumerate() {
+
+				//compensated Horner is close enough to exact to check the bounds against
+				let (reference, _) = poly.evaluateAtHornerCompensated(root);
+
+				//the bounds are for exactly the values the plain evaluators give
+				let (value, bound, count) = poly.evaluateAtHornerWithBound(root);
+				assert!((value - reference).norm() <= bound);
+				assert_eq!((value, count), poly.evaluateAtHorner(root));
+
+				let (value, bound, count) = poly.evaluateAtNaiveWithBound(root);
+				assert!((value - reference).norm() <= bound);
+				assert_eq!((value, count), poly.evaluateAtNaive(root));
+
+				let (value, bound, count) = poly.evaluateAtNaiveImprovedWithBound(root);
+				assert!((value - reference).norm() <= bound);
+				assert_eq!((value, count), poly.evaluateAtNaiveImproved(root));
+
+				assert!((fftValues[k] - reference).norm() <= fftBounds[k] + rootError);
+
+			}
+
+		}
+
+		//real coefficients go through the half length transform, power of two or not
+		for &n in &[16, 12, 1024] {
+
+			let poly: Polynomial = Polynomial::random(n, 10.0).unwrap().iter().map(|coeff| Complex64::new(coeff.re, 0.0)).collect();
+			let (fftValues, fftBounds, _) = poly.evaluateAtFFTWithBound();
+			let rootError: f64 = poly.iter().enumerate().map(|(i, coeff)| i as f64 * coeff.norm()).sum::<f64>() * (6.0 * PI + SQRT_2) * UNIT_ROUNDOFF;
+
+			assert_eq!(fftValues, poly.evaluateAtFFT().0);
+
+			for (k, root) in rootsOfUnity(n).into_iter().enumerate() {
+
+				let (reference, _) = poly.evaluateAtHornerCompensated(root);
+				assert!((fftValues[k] - reference).norm() <= fftBounds[k] + rootError);
+
+			}
+
+		}
+
+	}
+
+	#[test]
+	fn test_evaluate_estrin(){
+
+		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
+		let roots = rootsOfUnity(poly.len() as i32);
+
+		let mut attempt = Vec::with_capacity(poly.len());
+		let mut counts = 0;
+
+		for root in roots {
+
+			let (value, count) = poly.evaluateAtEstrin(root);
+			attempt.push(value);
+			counts += count;
+
+		}
+
+		compare_within_tolerance(&attempt, &answer);
+		assert_eq!(counts, 16);
+
+		//odd lengths carry the last term up a level
+		let poly = Polynomial::readFromFile(&"data/test_25.txt".to_string()).unwrap();
+		let x = Complex64::new(0.5, -0.25);
+
+		compare_within_tolerance(&[poly.evaluateAtEstrin(x).0], &[poly.evaluateAtHorner(x).0]);
+
+		//every mix of leftover blocks, with one squaring less than the levels it needs
+		for len in 1..40 {
+
+			let poly: Polynomial = (0..len).map(|i| Complex64::new(i as f64 - 3.0, 1.0 / (i as f64 + 1.0))).collect();
+			let (value, count) = poly.evaluateAtEstrin(x);
+			let levels = (len as f64).log2().ceil() as usize;
+
+			compare_within_tolerance(&[value], &[poly.evaluateAtHorner(x).0]);
+			assert_eq!(count, len - 1 + levels.saturating_sub(1));
+
+		}
+
+	}
+
+	#[test]
+	fn test_evaluate_naive_improved(){
+
+		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
+		let roots = rootsOfUnity(poly.len() as i32);
+
+		let mut attempt = Vec::with_capacity(poly.len());
+		let mut counts = 0;
+
+		for root in roots {
+
+			let(value, count) = poly.evaluateAtNaiveImproved(root);
+			attempt.push(value);
+			counts += count;
+
+		}
+
+		compare_within_tolerance(&attempt, &answer);
+		assert_eq!(counts, 32);
+
+	}
+
+	#[test]
+	fn test_evaluate_fft(){
+
+		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
+		let (attempt, count) = poly.evaluateAtFFT();
+
+		compare_within_tolerance(&attempt, &answer);
+
+		//the coefficients are all real, so this is a 2 point transform and two twiddles
+		assert_eq!(count, 3);
+
+		let (attempt, count) = poly.evaluateAtFFTComplex();
+
+		compare_within_tolerance(&attempt, &answer);
+		assert_eq!(count, 4);
 
 	}
 
-	fn random(degree: i32, bounds: f64) -> Result<Polynomial, String> {
+	#[test]
+	fn test_evaluate_fft_real(){
 
-		if degree < 0 {
+		for &n in &[4, 6, 50, 1024, 1500] {
 
-			return Err("You can't have a polynomial of degree less than zero.".to_string());
+			let poly: Polynomial = Polynomial::random(n, 5.0).unwrap().iter().map(|coeff| Complex64::new(coeff.re, 0.0)).collect();
 
-		} else if bounds <= 0.0 {
+			let (realAnswers, realCount) = poly.evaluateAtFFT();
+			let (complexAnswers, complexCount) = poly.evaluateAtFFTComplex();
 
-			return Err("Range is negative or zero.".to_string());
+			compare_within_tolerance(&realAnswers, &complexAnswers);
+			assert!(realCount < complexCount);
 
 		}
 
-		let mut poly = Polynomial::with_capacity(degree as usize);
+	}
 
-		let range = Range::new(-1.0 * bounds, bounds);
-		let mut rng = thread_rng();
+	#[test]
+	fn test_evaluate_fft_count(){
 
-		for _ in 0..degree {
+		let poly = Polynomial::readFromFile(&"data/poly_1024.txt".to_string()).unwrap();
+		let (_, count) = poly.evaluateAtFFT();
 
-			let real: f64 = range.ind_sample(&mut rng);
-			let imag: f64 = range.ind_sample(&mut rng);
+		//n/2 butterflies on each of the log2(n) levels
+		assert_eq!(count, 512 * 10);
 
-			poly.push(Complex64::new(real, imag));
+	}
 
-		}
+	#[test]
+	fn test_evaluate_fft_any_length(){
 
-		return Ok(poly);
+		for n in 1..301 {
 
-	}
+			let poly = Polynomial::random(n, 1.0).unwrap();
+			let roots = rootsOfUnity(n);
 
-	fn print(&self) -> String {
+			let mut hornerAnswers = Vec::with_capacity(n as usize);
 
-		let mut string = String::new();
+			for root in roots {
 
-		for term in 0..self.len() {
+				let (hornerAnswer, _) = poly.evaluateAtHorner(root);
+				hornerAnswers.push(hornerAnswer);
 
-			let real = self[term].re;
-			let imag = self[term].im;
-			let sign;
+			}
 
-			if imag < 0.0 {
+			let (fftAnswers, _) = poly.evaluateAtFFT();
 
-				sign = "-";
+			compare_within_tolerance(&hornerAnswers, &fftAnswers);
 
-			} else {
+		}
 
-				sign = "+";
+	}
 
-			}
+	#[test]
+	fn test_evaluate_fft_mixed_radix(){
+
+		for &n in &[1000, 1500, 3000, 7 * 7 * 5 * 3] {
 
-			string.push_str(&format!("({:.5} {} {:.5}i)(x^{})", real, sign, imag.abs(), term));
+			let poly = Polynomial::random(n, 1.0).unwrap();
+			let roots = rootsOfUnity(n);
 
-			if term < self.len() - 1 {
+			let mut hornerAnswers = Vec::with_capacity(n as usize);
 
-				string.push_str(" + ");
+			for root in roots {
+
+				let (hornerAnswer, _) = poly.evaluateAtHorner(root);
+				hornerAnswers.push(hornerAnswer);
 
 			}
 
+			let (fftAnswers, _) = poly.evaluateAtFFT();
+
+			compare_within_tolerance(&hornerAnswers, &fftAnswers);
+
 		}
 
-		return string;
+		//one twiddle for each of the last two inputs, then the two constant multiplies
+		let (_, count) = Polynomial::random(3, 1.0).unwrap().evaluateAtFFT();
+		assert_eq!(count, 4);
 
 	}
 
-	fn writeToFile(&self, filename: &String) -> Result<(), String> {
+	#[test]
+	fn test_fft_plan(){
 
-		//attempt to open the file
-		let file = File::create(filename.trim());
-		if file.is_err() { return Err("Failed to create file.".to_string()); }
+		let plan = FftPlan::new(2048).unwrap();
 
-		let mut outFile = BufWriter::new(file.unwrap());
+		for _ in 0..3 {
 
-		//first, we write the degree and a newline
-		let result = outFile.write(format!("{}\n", self.len()).as_bytes());
+			let poly = Polynomial::random(2048, 5.0).unwrap();
+			let (attempt, count) = plan.evaluate(&poly).unwrap();
+			let (answer, answerCount) = poly.evaluateAtFFT();
 
-		if let Err(_) = result {
+			compare_within_tolerance(&attempt, &answer);
+			assert_eq!(count, answerCount);
 
-			return Err("Could not write to file.".to_string());
+			let mut values = attempt.clone();
+			plan.inverseTransform(&mut values).unwrap();
+
+			compare_within_tolerance(&values, &poly);
 
 		}
 
-		//then, for each element, write "real,imag\n"
-		for item in self {
+		assert_eq!(FftPlan::new(1000).err(), Some("An FFT plan needs a power of two length, not 1000.".to_string()));
 
-			let result = outFile.write(format!("{},{}\n", item.re, item.im).as_bytes());
+		//the wrong length is an error, and leaves the values alone
+		let mut values = vec![Complex64::new(1.0, 0.0); 1024];
+		assert_eq!(plan.transform(&mut values), Err("This plan is for length 2048, not 1024.".to_string()));
+		assert_eq!(plan.inverseTransform(&mut values), Err("This plan is for length 2048, not 1024.".to_string()));
+		assert!(values.iter().all(|&value| value == Complex64::new(1.0, 0.0)));
 
-			if let Err(_) = result {
+	}
 
-				return Err("Could not write to file.".to_string());
+	#[test]
+	fn test_evaluate_fft_parallel(){
 
-			}
+		let poly = Polynomial::random(PARALLEL_CUTOFF as i32 * 2, 5.0).unwrap();
+		let (serial, serialCount) = poly.evaluateAtFFT();
+
+		for &threads in &[1, 2, 3, 8] {
+
+			let (parallel, parallelCount) = poly.evaluateAtFFTParallel(threads);
+
+			assert_eq!(parallel, serial);
+			assert_eq!(parallelCount, serialCount);
 
 		}
 
-		return Ok(());
+		assert_eq!(poly.parallelFFTThreads(3), 2);
+		assert_eq!(parallelThreadCount(PARALLEL_CUTOFF / 2, 8), 1);
 
 	}
 
-	fn evaluateAtNaive(&self, x: Complex64) -> (Complex64, usize)  {
+	#[test]
+	fn test_evaluate_fft_parallel_many_threads(){
 
-		let mut count: usize = 0;
-		let mut sum = Complex64::new(0.0, 0.0);
+		//more threads than the square root of the length would leave some of them
+		//with empty pieces, so they get capped there
+		let poly = Polynomial::random(PARALLEL_CUTOFF as i32, 5.0).unwrap();
+		let (serial, serialCount) = poly.evaluateAtFFT();
+		let (parallel, parallelCount) = poly.evaluateAtFFTParallel(1 << 20);
 
-		for (i, coeff) in self.iter().enumerate() {
+		assert_eq!(parallel, serial);
+		assert_eq!(parallelCount, serialCount);
 
-			sum = sum + (coeff * x.powf(i as f64));
-			count += 1 + i;
+		assert_eq!(poly.parallelFFTThreads(1 << 20), 256);
+		assert_eq!(parallelThreadCount(PARALLEL_CUTOFF * 2, usize::MAX), 256);
 
-		}
+	}
 
-		return (sum, count);
+	#[test]
+	fn test_evaluate_goertzel(){
 
-	}
+		let poly = Polynomial::readFromFile(&"data/poly_0128.txt".to_string()).unwrap();
+		let (fftAnswers, _) = poly.evaluateAtFFT();
 
-	fn evaluateAtHorner(&self, x: Complex64) -> (Complex64, usize)  {
+		let bins = [0, 1, 17, 64, 127, 128 + 5];
+		let (attempt, count) = poly.evaluateAtGoertzel(&bins);
 
-		let mut count: usize = 0;
-		let mut sum = Complex64::new(0.0, 0.0);
+		let answer: Vec<Complex64> = bins.iter().map(|&k| fftAnswers[k % 128]).collect();
 
-		for coeff in self.iter().rev() {
+		compare_within_tolerance(&attempt, &answer);
+		assert_eq!(count, bins.len() * 129);
 
-			sum = (sum * x) + coeff;
-			count += 1;
+	}
 
-		}
+	#[test]
+	fn test_evaluate_at_points(){
 
-		return (sum, count);
+		let poly = Polynomial::readFromFile(&"data/poly_0256.txt".to_string()).unwrap();
+		let points = Polynomial::random(300, 0.4).unwrap();
+
+		let (attempt, _) = poly.evaluateAtPoints(&points);
+		let answer: Vec<Complex64> = points.iter().map(|&point| poly.evaluateAtHorner(point).0).collect();
+
+		compare_within_tolerance(&attempt, &answer);
+
+		let (attempt, _) = poly.evaluateAtPoints(&[Complex64::new(0.0, 0.0)]);
+		compare_within_tolerance(&attempt, &[poly[0]]);
 
 	}
 
-	fn evaluateAtNaiveImproved(&self, x: Complex64) -> (Complex64, usize) {
+	#[test]
+	fn test_interpolate_fft(){
 
-		let mut count: usize = 0;
-		let mut sum = Complex64::new(0.0, 0.0);
-		let mut xPower = Complex64::new(1.0, 0.0);
+		let files = ["data/poly_0004.txt", "data/poly_0016.txt", "data/poly_0128.txt", "data/poly_1024.txt", "data/poly_2048.txt", "data/test_25.txt"];
 
-		for coeff in self {
+		for file in files.iter() {
 
-			sum = sum + (coeff * xPower);
-			xPower = xPower * x;
-			count += 2;
+			let poly = Polynomial::readFromFile(&file.to_string()).unwrap();
+			let (values, _) = poly.evaluateAtFFT();
+			let (attempt, _) = Polynomial::interpolateFFT(&values);
+
+			compare_within_tolerance(&attempt, &poly);
 
 		}
 
-		return (sum, count);
+	}
+
+	#[test]
+	fn test_multiply_schoolbook(){
+
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let (product, count) = poly.multiplySchoolbook(&poly);
+
+		let answer: Polynomial = [1.0, 4.0, 10.0, 20.0, 25.0, 24.0, 16.0].iter().map(|&re| Complex64::new(re, 0.0)).collect();
+
+		compare_within_tolerance(&product, &answer);
+		assert_eq!(count, 16);
 
 	}
 
-	fn evaluateAtFFT(&self) -> (Vec<Complex64>, usize) {
+	#[test]
+	fn test_multiply_fft(){
 
-		let mut count: usize = 0;
-		let n = self.len();
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let (product, count) = poly.multiplyFFT(&poly);
 
-		//base case
-		if n == 1 {
+		let answer: Polynomial = [1.0, 4.0, 10.0, 20.0, 25.0, 24.0, 16.0].iter().map(|&re| Complex64::new(re, 0.0)).collect();
 
-			return (vec![self[0]], count);
+		//three transforms of length 8 with 12 multiplies each, plus 8 pointwise
+		compare_within_tolerance(&product, &answer);
+		assert_eq!(count, 44);
 
-		} else if n == 0 {
+		let left = Polynomial::readFromFile(&"data/poly_1024.txt".to_string()).unwrap();
+		let right = Polynomial::readFromFile(&"data/test_25.txt".to_string()).unwrap();
 
-			return (vec![], count);
+		let (schoolbook, _) = left.multiplySchoolbook(&right);
+		let (fft, _) = left.multiplyFFT(&right);
 
-		}
+		compare_within_tolerance(&schoolbook, &fft);
+
+	}
 
-		//split the list into even and odd pairs
-		let mut even = Polynomial::new();
-		let mut odd = Polynomial::new();
+	#[test]
+	fn test_polynomial_ops(){
 
-		for (i, coeff) in self.iter().enumerate() {
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let short = Polynomial::from(vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0)]);
 
-			if (i % 2) == 0 {
+		//the sum keeps the longer length
+		let sum = &poly + &short;
+		assert_eq!(sum.len(), poly.len());
+		assert_eq!(sum[0], poly[0] + short[0]);
+		assert_eq!(sum[1], poly[1] + short[1]);
+		assert_eq!(sum[3], poly[3]);
 
-				even.push(*coeff);
+		let difference = &sum - &short;
+		compare_within_tolerance(&difference, &poly);
+		compare_within_tolerance(&(-&poly + poly.clone()), &vec![Complex64::new(0.0, 0.0); poly.len()]);
 
-			} else {
+		let (answer, _) = poly.multiplySchoolbook(&short);
+		compare_within_tolerance(&(poly.clone() * short.clone()), &answer);
 
-				odd.push(*coeff);
+		let scale = Complex64::new(2.0, -1.0);
+		let scaled = scale * poly.clone();
 
-			}
+		for (term, coeff) in scaled.iter().enumerate() {
+
+			assert_eq!(*coeff, poly[term] * scale);
 
 		}
 
-		//evaluate the evens and odds
-		let (e, eCount) = even.evaluateAtFFT();
-		let (d, dCount) = odd.evaluateAtFFT();
+		let coeffs: Vec<Complex64> = poly.clone().into();
+		assert_eq!(Polynomial::from(coeffs), poly);
 
-		count += eCount + dCount;
+	}
 
-		//calculate the new answers
-		let mut answer = Vec::with_capacity(n);
-		unsafe {answer.set_len(n);}
+	#[test]
+	fn test_divide(){
 
-		for k in 0..n / 2 {
+		let poly = Polynomial::readFromFile(&"data/poly_0064.txt".to_string()).unwrap();
+		let divisor = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
 
-			let root = rootOfUnity(n as i32, k as i32);
-			let right = root * d[k];
-			count += 1;
+		let (quotient, remainder, _) = poly.divide(&divisor, 1e-12).unwrap();
+		assert_eq!(quotient.len(), poly.len() - divisor.len() + 1);
+		assert_eq!(remainder.len(), divisor.len() - 1);
 
-			let y0 = e[k] + right;
-			let y1 = e[k] - right;
+		//putting it back together should give the dividend
+		let (product, _) = quotient.multiplySchoolbook(&divisor);
+		compare_within_tolerance(&(&product + &remainder), &poly);
 
-			answer[k] = y0;
-			answer[k + (n / 2)] = y1;
+		//a zero on top of the divisor is ignored
+		let mut padded = divisor.to_vec();
+		padded.push(Complex64::new(1e-20, 0.0));
+		let (paddedQuotient, _, _) = poly.divide(&Polynomial::from(padded), 1e-12).unwrap();
+		compare_within_tolerance(&paddedQuotient, &quotient);
 
-		}
+		//a shorter dividend is all remainder, which is still one shorter than the divisor
+		let (quotient, remainder, _) = divisor.divide(&poly, 1e-12).unwrap();
+		let mut answer = divisor.to_vec();
+		answer.resize(poly.len() - 1, Complex64::new(0.0, 0.0));
+
+		assert!(quotient.is_empty());
+		assert_eq!(remainder.to_vec(), answer);
 
-		return (answer, count);
+		let zero = Polynomial::from(vec![Complex64::new(0.0, 0.0)]);
+		assert_eq!(poly.divide(&zero, 1e-12), Err("Cannot divide by the zero polynomial.".to_string()));
 
 	}
 
-}
+	#[test]
+	fn test_gcd(){
 
-#[cfg(test)]
-mod tests {
+		//(x - 1)(x + 2) and (x - 1)(x - 3) share only (x - 1)
+		let left = Polynomial::from(vec![Complex64::new(-2.0, 0.0), Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)]);
+		let right = Polynomial::from(vec![Complex64::new(3.0, 0.0), Complex64::new(-4.0, 0.0), Complex64::new(1.0, 0.0)]);
 
-	use super::*;
-	use num_complex::Complex64;
+		let (divisor, _) = left.gcd(&right, 1e-10).unwrap();
+		compare_within_tolerance(&divisor, &[Complex64::new(-1.0, 0.0), Complex64::new(1.0, 0.0)]);
 
-	const TOLERANCE: f64 = 0.0001;
+		//a polynomial times something shares all of itself with the product
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let (product, _) = poly.multiplySchoolbook(&right);
+		let (divisor, _) = product.gcd(&poly, 1e-10).unwrap();
+		let monic = &poly * (Complex64::new(1.0, 0.0) / poly[poly.len() - 1]);
 
-	fn compare_within_tolerance(left: &Polynomial, right: &Polynomial){
+		compare_within_tolerance(&divisor, &monic);
 
-		if left.len() != right.len() {
+		let zero = Polynomial::new();
+		assert!(zero.gcd(&zero, 1e-10).is_err());
 
-			panic!("Lengths are not equal. Left: {:?}, Right: {:?}", left, right);
+	}
 
-		}
+	#[test]
+	fn test_divide_synthetic(){
 
-		for i in 0..left.len() {
+		let poly = Polynomial::readFromFile(&"data/poly_0128.txt".to_string()).unwrap();
+		let a = Complex64::new(0.3, -0.7);
 
-			let diff = left[i] - right[i];
+		let (quotient, remainder, count) = poly.divideSynthetic(a);
+		let (value, hornerCount) = poly.evaluateAtHorner(a);
 
-			let diffReal = diff.re.abs();
-			let diffImag = diff.im.abs();
+		assert_eq!(remainder, value);
+		assert_eq!(count, hornerCount);
 
-			if diffReal > TOLERANCE {
+		let (answer, answerRemainder, _) = poly.divide(&Polynomial::from(vec![-a, Complex64::new(1.0, 0.0)]), 1e-12).unwrap();
+		compare_within_tolerance(&quotient, &answer);
+		compare_within_tolerance(&[remainder], &answerRemainder);
 
-				panic!("Difference on real part {} is greater than tolerance {}. Index: {}, Left: {:?}, Right: {:?}", diffReal, TOLERANCE, i, left, right);
+	}
 
-			} else if diffImag > TOLERANCE {
+	#[test]
+	fn test_compose(){
 
-				panic!("Difference on imag part {} is greater than tolerance {}. Index: {}, Left: {:?}, Right: {:?}", diffImag, TOLERANCE, i, left, right);
+		//p(x) = 1 + 2x + 3x^2 + 4x^3 at q(x) = x + 1 is 10 + 20x + 15x^2 + 4x^3
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let shift = Polynomial::from(vec![Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0)]);
+		let answer: Polynomial = [10.0, 20.0, 15.0, 4.0].iter().map(|&re| Complex64::new(re, 0.0)).collect();
 
-			}
+		let (horner, _) = poly.composeHorner(&shift);
+		let (fft, _) = poly.composeFFT(&shift);
+
+		compare_within_tolerance(&horner, &answer);
+		compare_within_tolerance(&fft, &answer);
+
+		//the composition has to agree with evaluating p at q(x) for any x
+		for n in [1, 2, 5, 17, 40].iter() {
+
+			let outer = Polynomial::random(*n, 1.0).unwrap();
+			let inner = Polynomial::random(4, 0.5).unwrap();
+			let x = Complex64::new(0.3, -0.4);
+
+			let (horner, _) = outer.composeHorner(&inner);
+			let (fft, _) = outer.composeFFT(&inner);
+
+			assert_eq!(fft.len(), (*n as usize - 1) * 3 + 1);
+			compare_within_tolerance(&horner, &fft);
+			compare_within_tolerance(&[fft.evaluateAtHorner(x).0], &[outer.evaluateAtHorner(inner.evaluateAtHorner(x).0).0]);
 
 		}
 
 	}
 
 	#[test]
-	fn test_roots_of_unity(){
+	fn test_derivative_integral(){
 
-		let roots = rootsOfUnity(1);
-		compare_within_tolerance(&roots, &vec![Complex64::new(1.0, 0.0)]);
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
 
-		let roots = rootsOfUnity(2);
-		compare_within_tolerance(&roots, &vec![Complex64::new(1.0, 0.0), Complex64::new(-1.0, 0.0)]);
+		let (derivative, count) = poly.derivative();
+		let answer: Polynomial = [2.0, 6.0, 12.0].iter().map(|&re| Complex64::new(re, 0.0)).collect();
 
-		let roots = rootsOfUnity(4);
-		compare_within_tolerance(&roots, &vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0), Complex64::new(-1.0, 0.0), Complex64::new(0.0, -1.0)]);
+		assert_eq!(derivative, answer);
+		assert_eq!(count, 3);
+
+		//integrating undoes differentiating, given the constant term back
+		let (integral, _) = derivative.integral(poly[0]);
+		compare_within_tolerance(&integral, &poly);
+
+		let x = Complex64::new(0.6, 0.2);
+		let (values, _) = poly.evaluateDerivativesAtHorner(x, 1);
+		compare_within_tolerance(&[derivative.evaluateAtHorner(x).0], &values[1..]);
 
 	}
 
 	#[test]
-	fn test_read_file(){
+	fn test_taylor_shift(){
 
-		let result = Polynomial::readFromFile(&"./data/file_valid.txt".to_string()).unwrap();
-		assert_eq!(result, vec![Complex64::new(1.0,0.0), Complex64::new(2.0,3.25), Complex64::new(3.25,1.0)]);
+		//p(x) = 1 + 2x + 3x^2 + 4x^3 shifted by 1 is 10 + 20x + 15x^2 + 4x^3
+		let poly = Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
+		let a = Complex64::new(1.0, 0.0);
+		let answer: Polynomial = [10.0, 20.0, 15.0, 4.0].iter().map(|&re| Complex64::new(re, 0.0)).collect();
 
-	}
+		let (horner, hornerCount) = poly.taylorShiftHorner(a);
+		let (fft, _) = poly.taylorShiftFFT(a);
 
-	#[test]
-	fn test_read_file_nonexistant(){
+		compare_within_tolerance(&horner, &answer);
+		compare_within_tolerance(&fft, &answer);
+		assert_eq!(hornerCount, 6);
 
-		let result = Polynomial::readFromFile(&"./data/file_nonexistant.txt".to_string());
-		assert_eq!(result, Err("Failed to open file.".to_string()));
+		//long enough for the multiplies to go through the FFT
+		for n in [40, 64].iter() {
 
-	}
+			let poly = Polynomial::random(*n, 1.0).unwrap();
+			let a = Complex64::new(0.2, -0.3);
 
-	#[test]
-	fn test_read_file_zero_degree(){
+			let (horner, _) = poly.taylorShiftHorner(a);
+			let (fft, _) = poly.taylorShiftFFT(a);
 
-		let result = Polynomial::readFromFile(&"./data/file_valid_zero_degree.txt".to_string()).unwrap();
-		assert_eq!(result, Vec::new());
+			//the coefficients grow into the millions, so check against the largest of them
+			let largest = horner.iter().map(|coeff| coeff.norm()).fold(0.0, f64::max);
 
-	}
+			for (left, right) in horner.iter().zip(fft.iter()) {
 
-	#[test]
-	fn test_read_file_invalid_has_junk_degree(){
+				assert!((left - right).norm() < 1e-9 * largest, "{} != {}", left, right);
 
-		let result = Polynomial::readFromFile(&"./data/file_invalid_has_junk_degree.txt".to_string());
-		assert_eq!(result, Err("Parsing failed. Invalid syntax \'junk\' in file.".to_string()));
+			}
+
+			let x = Complex64::new(0.1, 0.4);
+			compare_within_tolerance(&[fft.evaluateAtHorner(x).0], &[poly.evaluateAtHorner(x + a).0]);
+
+		}
 
 	}
 
-	#[test]
-	fn test_read_file_invalid_has_junk_coeffs(){
+	///Integers mod 17, standing in for a user defined scalar. 3 generates every
+	///nonzero value, so there are roots of unity for every length dividing 16. It is
+	///only Clone, like a big number type would be.
+	#[derive(Clone, Debug, PartialEq)]
+	struct Mod17(u32);
 
-		let result = Polynomial::readFromFile(&"./data/file_invalid_has_junk.txt".to_string());
-		assert_eq!(result, Err("Parsing failed. Invalid syntax \'junk\' in file.".to_string()));
+	impl Add for Mod17 {
 
-	}
+		type Output = Mod17;
 
-	#[test]
-	fn test_read_file_invalid_has_more_coeffs(){
+		fn add(self, other: Mod17) -> Mod17 {
 
-		let result = Polynomial::readFromFile(&"./data/file_invalid_more_coeffs.txt".to_string());
-		assert_eq!(result, Err("Incorrect number of coefficients, 4 instead of 3.".to_string()));
+			return Mod17((self.0 + other.0) % 17);
+
+		}
 
 	}
 
-	#[test]
-	fn test_random_poly(){
+	impl Sub for Mod17 {
 
-		let result = Polynomial::random(3, 5.0).unwrap();
-		assert_eq!(result.len(), 3);
+		type Output = Mod17;
 
-	}
+		fn sub(self, other: Mod17) -> Mod17 {
 
-	#[test]
-	fn test_random_poly_zero_degree(){
+			return Mod17((self.0 + 17 - other.0) % 17);
 
-		let result = Polynomial::random(-1, 5.0);
-		assert_eq!(result, Err("You can't have a polynomial of degree less than zero.".to_string()));
+		}
 
 	}
 
-	#[test]
-	fn test_random_poly_zero_range(){
+	impl Mul for Mod17 {
 
-		let result = Polynomial::random(3, -5.0);
-		assert_eq!(result, Err("Range is negative or zero.".to_string()));
+		type Output = Mod17;
 
-	}
+		fn mul(self, other: Mod17) -> Mod17 {
 
-	#[test]
-	fn test_evaluate_naive(){
+			return Mod17((self.0 * other.0) % 17);
 
-		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
-		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
-		let roots = rootsOfUnity(poly.len() as i32);
+		}
 
-		let mut attempt = Vec::with_capacity(poly.len());
-		let mut counts = 0;
+	}
 
-		for root in roots {
+	impl Neg for Mod17 {
 
-			let (value, count) = poly.evaluateAtNaive(root);
-			attempt.push(value);
-			counts += count;
+		type Output = Mod17;
 
-		}
+		fn neg(self) -> Mod17 {
 
-		compare_within_tolerance(&attempt, &answer);
-		assert_eq!(counts, 40);
+			return Mod17((17 - self.0) % 17);
+
+		}
 
 	}
 
-	#[test]
-	fn test_evaluate_horner(){
+	impl Scalar for Mod17 {
 
-		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
-		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
-		let roots = rootsOfUnity(poly.len() as i32);
+		fn zero() -> Mod17 {
 
-		let mut attempt = Vec::with_capacity(poly.len());
-		let mut counts = 0;
+			return Mod17(0);
 
-		for root in roots {
+		}
 
-			let (value, count) = poly.evaluateAtHorner(root);
-			attempt.push(value);
-			counts += count;
+		fn one() -> Mod17 {
+
+			return Mod17(1);
 
 		}
 
-		compare_within_tolerance(&attempt, &answer);
-		assert_eq!(counts, 16);
+	}
+
+	impl RootOfUnity for Mod17 {
+
+		fn rootOfUnity(n: usize, k: usize) -> Mod17 {
+
+			return Mod17(3).powi(16 / n * k);
+
+		}
 
 	}
 
 	#[test]
-	fn test_evaluate_naive_improved(){
+	fn test_generic_scalars(){
 
-		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
-		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
-		let roots = rootsOfUnity(poly.len() as i32);
+		//1 + 2x + 3x^2 + 4x^3 at 0.5 is 3.25
+		let real: Polynomial<f64> = Polynomial::from(vec![1.0, 2.0, 3.0, 4.0]);
+		assert_eq!(real.evaluateAtHorner(0.5), (3.25, 4));
+		assert_eq!(real.evaluateAtNaive(0.5).0, 3.25);
+		assert_eq!(real.evaluateAtNaiveImproved(0.5).0, 3.25);
 
-		let mut attempt = Vec::with_capacity(poly.len());
-		let mut counts = 0;
+		let single: Polynomial<f32> = real.iter().map(|&coeff| coeff as f32).collect();
+		assert_eq!(single.evaluateAtHorner(0.5).0, 3.25);
 
-		for root in roots {
+		//single precision complex agrees with double precision to about single's precision
+		let poly = Polynomial::readFromFile(&"data/poly_0064.txt".to_string()).unwrap();
+		let narrow: Polynomial<Complex32> = poly.iter().map(|coeff| Complex32::new(coeff.re as f32, coeff.im as f32)).collect();
 
-			let(value, count) = poly.evaluateAtNaiveImproved(root);
-			attempt.push(value);
-			counts += count;
+		let (values, count) = poly.evaluateAtFFT();
+		let (narrowValues, narrowCount) = narrow.evaluateAtFFTRadix2().unwrap();
+
+		assert_eq!(narrowCount, count);
+
+		for (value, narrowValue) in values.iter().zip(narrowValues.iter()) {
+
+			assert!((value - Complex64::new(narrowValue.re as f64, narrowValue.im as f64)).norm() < 1e-3);
 
 		}
 
-		compare_within_tolerance(&attempt, &answer);
-		assert_eq!(counts, 32);
+		let roots: Vec<Complex32> = rootsOfUnity(64);
 
-	}
+		for (narrowValue, &root) in narrowValues.iter().zip(roots.iter()) {
 
-	#[test]
-	fn test_evaluate_fft(){
+			assert!((narrowValue - narrow.evaluateAtHorner(root).0).norm() < 1e-3);
 
-		let poly 	= Polynomial::readFromFile(&"data/test.txt".to_string()).unwrap();
-		let answer 	= Polynomial::readFromFile(&"data/test_answers.txt".to_string()).unwrap();
-		let (attempt, count) = poly.evaluateAtFFT();
+		}
 
-		compare_within_tolerance(&attempt, &answer);
-		assert_eq!(count, 4);
+		//the FFT over a user defined scalar is exact
+		let modular: Polynomial<Mod17> = [5, 0, 16, 3, 9, 1, 2, 11].iter().map(|&coeff| Mod17(coeff)).collect();
+		let (modularValues, _) = modular.evaluateAtFFTRadix2().unwrap();
+		let modularRoots: Vec<Mod17> = rootsOfUnity(8);
+
+		for (value, root) in modularValues.iter().zip(modularRoots.iter()) {
+
+			assert_eq!(*value, modular.evaluateAtHorner(root.clone()).0);
+			assert_eq!(*value, modular.evaluateAtNaive(root.clone()).0);
+
+		}
+
+		assert!(Polynomial::from(vec![Mod17(1); 3]).evaluateAtFFTRadix2().is_err());
 
 	}
 
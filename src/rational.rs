@@ -0,0 +1,529 @@
+
+use std::fmt;
+use std::str;
+use std::fs::File;
+use std::io::Read;
+use std::str::FromStr;
+use std::ops::{Add, Sub, Mul, Neg};
+
+use nom::digit;
+use nom::IResult::*;
+use nom::Err::*;
+
+use bigint::BigInt;
+use poly::{Polynomial, integer, get_min_err};
+use scalar::Scalar;
+
+///An exact fraction. The denominator is always positive, and the fraction is kept
+///in lowest terms, so equal values always have equal parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rational {
+
+	numerator: BigInt,
+	denominator: BigInt
+
+}
+
+///An exact complex number, with a rational real and imaginary part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GaussianRational {
+
+	pub re: Rational,
+	pub im: Rational
+
+}
+
+//Create a function named "fraction" that looks for an optional negative sign,
+//some digits, and optionally a slash followed by more digits. It returns the slice
+//covering all of it, which Rational::from_str turns into a value.
+named!(fraction, recognize!(chain!(
+    opt!(tag!("-")) ~
+    digit ~
+    opt!(complete!(chain!(
+        tag!("/") ~
+        digit ,
+        || ()
+    ))) ,
+    || ()
+)));
+
+//Create a function named "rationalpolyfile" that returns a tuple of an i64 and the
+//text of each coefficient's real and imaginary parts. It is the same as "polyfile",
+//except the parts are fractions instead of floating point numbers.
+named!(rationalpolyfile <(i64, Vec<(String, String)>)>,
+    chain!(
+        deg: integer ~
+        many1!(tag!("\n")) ~
+        coeffs: many0!(complete!(chain!(
+            many0!(tag!("\n")) ~
+            re: fraction ~
+            tag!(",") ~
+            many0!(alt!(tag!(" ") | tag!("\t"))) ~
+            im: fraction ,
+            || (String::from_utf8_lossy(re).into_owned(), String::from_utf8_lossy(im).into_owned())
+        ))) ,
+        || {
+            return (deg, coeffs)
+        })
+);
+
+impl Rational {
+
+	///Builds the fraction numerator / denominator in lowest terms.
+	///Returns an error message if the denominator is zero.
+	pub fn new(numerator: BigInt, denominator: BigInt) -> Result<Rational, String> {
+
+		if denominator.isZero() {
+
+			return Err("The denominator of a fraction cannot be zero.".to_string());
+
+		}
+
+		let divisor = numerator.gcd(&denominator);
+		let (mut numerator, _) = numerator.divRem(&divisor);
+		let (mut denominator, _) = denominator.divRem(&divisor);
+
+		if denominator.isNegative() {
+
+			numerator = -&numerator;
+			denominator = -&denominator;
+
+		}
+
+		return Ok(Rational { numerator, denominator });
+
+	}
+
+	pub fn zero() -> Rational {
+
+		return Rational::from(0);
+
+	}
+
+	///Builds a fraction from parts that are already known to be valid.
+	fn reduced(numerator: BigInt, denominator: BigInt) -> Rational {
+
+		return Rational::new(numerator, denominator).unwrap();
+
+	}
+
+	pub fn isNegative(&self) -> bool {
+
+		return self.numerator.isNegative();
+
+	}
+
+}
+
+impl From<i64> for Rational {
+
+	fn from(value: i64) -> Rational {
+
+		return Rational { numerator: BigInt::from(value), denominator: BigInt::from(1) };
+
+	}
+
+}
+
+impl FromStr for Rational {
+
+	type Err = String;
+
+	///Reads an integer like "-3" or a fraction like "-3/4".
+	fn from_str(text: &str) -> Result<Rational, String> {
+
+		let mut parts = text.splitn(2, '/');
+		let numerator = parts.next().unwrap().parse()?;
+
+		let denominator = match parts.next() {
+
+			Some(denominator) => denominator.parse()?,
+			None => BigInt::from(1)
+
+		};
+
+		return Rational::new(numerator, denominator);
+
+	}
+
+}
+
+impl fmt::Display for Rational {
+
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+		if self.denominator == BigInt::from(1) {
+
+			return write!(f, "{}", self.numerator);
+
+		}
+
+		return write!(f, "{}/{}", self.numerator, self.denominator);
+
+	}
+
+}
+
+impl Add<&Rational> for &Rational {
+
+	type Output = Rational;
+
+	fn add(self, other: &Rational) -> Rational {
+
+		let numerator = &(&self.numerator * &other.denominator) + &(&other.numerator * &self.denominator);
+
+		return Rational::reduced(numerator, &self.denominator * &other.denominator);
+
+	}
+
+}
+
+impl Sub<&Rational> for &Rational {
+
+	type Output = Rational;
+
+	fn sub(self, other: &Rational) -> Rational {
+
+		return self + &(-other);
+
+	}
+
+}
+
+impl Mul<&Rational> for &Rational {
+
+	type Output = Rational;
+
+	fn mul(self, other: &Rational) -> Rational {
+
+		return Rational::reduced(&self.numerator * &other.numerator, &self.denominator * &other.denominator);
+
+	}
+
+}
+
+impl Neg for &Rational {
+
+	type Output = Rational;
+
+	fn neg(self) -> Rational {
+
+		return Rational { numerator: -&self.numerator, denominator: self.denominator.clone() };
+
+	}
+
+}
+
+impl GaussianRational {
+
+	pub fn new(re: Rational, im: Rational) -> GaussianRational {
+
+		return GaussianRational { re, im };
+
+	}
+
+}
+
+impl Scalar for GaussianRational {
+
+	fn zero() -> GaussianRational {
+
+		return GaussianRational::new(Rational::zero(), Rational::zero());
+
+	}
+
+	fn one() -> GaussianRational {
+
+		return GaussianRational::new(Rational::from(1), Rational::zero());
+
+	}
+
+}
+
+impl Add<&GaussianRational> for &GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn add(self, other: &GaussianRational) -> GaussianRational {
+
+		return GaussianRational::new(&self.re + &other.re, &self.im + &other.im);
+
+	}
+
+}
+
+impl Sub<&GaussianRational> for &GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn sub(self, other: &GaussianRational) -> GaussianRational {
+
+		return GaussianRational::new(&self.re - &other.re, &self.im - &other.im);
+
+	}
+
+}
+
+///Multiplies the usual way, with four rational products, counted as one multiply.
+impl Mul<&GaussianRational> for &GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn mul(self, other: &GaussianRational) -> GaussianRational {
+
+		let re = &(&self.re * &other.re) - &(&self.im * &other.im);
+		let im = &(&self.re * &other.im) + &(&self.im * &other.re);
+
+		return GaussianRational::new(re, im);
+
+	}
+
+}
+
+impl Neg for &GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn neg(self) -> GaussianRational {
+
+		return GaussianRational::new(-&self.re, -&self.im);
+
+	}
+
+}
+
+//the owned versions, which Scalar needs, all forward to the borrowed ones above
+impl Add for GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn add(self, other: GaussianRational) -> GaussianRational {
+
+		return &self + &other;
+
+	}
+
+}
+
+impl Sub for GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn sub(self, other: GaussianRational) -> GaussianRational {
+
+		return &self - &other;
+
+	}
+
+}
+
+impl Mul for GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn mul(self, other: GaussianRational) -> GaussianRational {
+
+		return &self * &other;
+
+	}
+
+}
+
+impl Neg for GaussianRational {
+
+	type Output = GaussianRational;
+
+	fn neg(self) -> GaussianRational {
+
+		return -&self;
+
+	}
+
+}
+
+impl FromStr for GaussianRational {
+
+	type Err = String;
+
+	///Reads the real and imaginary parts separated by a comma, like "1/3,-2/7".
+	fn from_str(text: &str) -> Result<GaussianRational, String> {
+
+		let parts: Vec<&str> = text.split(',').map(|part| part.trim()).collect();
+
+		if parts.len() != 2 {
+
+			return Err(format!("'{}' is not a real and imaginary part separated by a comma.", text.trim()));
+
+		}
+
+		return Ok(GaussianRational::new(parts[0].parse()?, parts[1].parse()?));
+
+	}
+
+}
+
+impl fmt::Display for GaussianRational {
+
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+		//the same sign handling as printTerms, so it reads 1/3 - 2/7i
+		if self.im.isNegative() {
+
+			return write!(f, "{} - {}i", self.re, -&self.im);
+
+		}
+
+		return write!(f, "{} + {}i", self.re, self.im);
+
+	}
+
+}
+
+///Reads a rational polynomial from a file with one coefficient per line, given as two
+///fractions like "1/3,-2/7". Returns the read polynomial on success, or an error
+///message on failure.
+pub fn readFromFile(filename: &str) -> Result<Polynomial<GaussianRational>, String> {
+
+	let file = File::open(filename.trim());
+	if file.is_err() { return Err("Failed to open file.".to_string()); }
+
+	let mut all_text = String::new();
+	let _ = file.unwrap().read_to_string(&mut all_text);
+
+	let (degrees, coeffs) = match rationalpolyfile(all_text.as_bytes()) {
+
+		Done(extra, (deg, coeffs)) => {
+
+			//there should be nothing left in the file, besides the newlines
+			//the parser stopped in front of
+			let extra_str = str::from_utf8(extra).unwrap().trim();
+
+			if extra_str.len() > 0 {
+
+				return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(extra_str.as_bytes())));
+
+			}
+
+			(deg, coeffs)
+
+		},
+		Error(Position(_, pos)) => return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(pos))),
+		_ => return Err("Parsing failed, invalid format.".to_string())
+
+	};
+
+	if degrees != coeffs.len() as i64 {
+
+		return Err(format!("Incorrect number of coefficients, {} instead of {}.", coeffs.len(), degrees));
+
+	}
+
+	//the parser only checked the syntax, so a zero denominator shows up here
+	let mut poly = Vec::with_capacity(coeffs.len());
+
+	for (re, im) in coeffs {
+
+		let re = re.parse()?;
+		let im = im.parse()?;
+
+		poly.push(GaussianRational::new(re, im));
+
+	}
+
+	return Ok(Polynomial::from(poly));
+
+}
+
+///Generates a pretty looking string of the coefficients to print to the console, the
+///same way printTerms does for complex ones.
+pub fn printTerms(terms: &[GaussianRational]) -> String {
+
+	let terms: Vec<String> = terms.iter().enumerate().map(|(term, coeff)| format!("({})(x^{})", coeff, term)).collect();
+
+	return terms.join(" + ");
+
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use poly::Evaluate;
+
+	fn rational(text: &str) -> Rational {
+
+		return text.parse().unwrap();
+
+	}
+
+	#[test]
+	fn test_rational_arithmetic(){
+
+		assert_eq!(rational("6/-8").to_string(), "-3/4");
+		assert_eq!(rational("-10/5").to_string(), "-2");
+		assert_eq!(&rational("1/3") + &rational("1/6"), rational("1/2"));
+		assert_eq!(&rational("1/3") - &rational("1/3"), Rational::zero());
+		assert_eq!(&rational("-2/7") * &rational("21/4"), rational("-3/2"));
+		assert_eq!(-&rational("-2/7"), rational("2/7"));
+		assert_eq!("1/0".parse::<Rational>(), Err("The denominator of a fraction cannot be zero.".to_string()));
+
+		//sums no f64 can hold exactly still come out exact
+		let mut sum = Rational::zero();
+
+		for k in 1..40 {
+
+			sum = &sum + &Rational::reduced(BigInt::from(1), BigInt::from(k * (k + 1)));
+
+		}
+
+		assert_eq!(sum, rational("39/40"));
+
+	}
+
+	#[test]
+	fn test_read_rational_file(){
+
+		let result = readFromFile("./data/rational_valid.txt").unwrap();
+
+		assert_eq!(printTerms(&result), "(1/3 - 2/7i)(x^0) + (-1/2 + 0i)(x^1) + (2 + 1/5i)(x^2)");
+
+	}
+
+	#[test]
+	fn test_read_rational_file_invalid(){
+
+		let result = readFromFile("./data/rational_invalid_has_junk.txt");
+		assert_eq!(result, Err("Parsing failed. Invalid syntax \'1.5,0\' in file.".to_string()));
+
+		let result = readFromFile("./data/rational_invalid_zero_denominator.txt");
+		assert_eq!(result, Err("The denominator of a fraction cannot be zero.".to_string()));
+
+	}
+
+	#[test]
+	fn test_evaluate_rational_horner(){
+
+		let poly = readFromFile("./data/rational_valid.txt").unwrap();
+
+		//at x = i, the sum is 1/3 - 2/7 i - 1/2 i - 2 - 1/5 i
+		let x: GaussianRational = "0,1".parse().unwrap();
+		let answer: GaussianRational = "-5/3,-69/70".parse().unwrap();
+
+		let (value, count) = poly.evaluateAtHorner(x.clone());
+		assert_eq!(value, answer);
+		assert_eq!(count, 3);
+
+		//the other evaluators come out exactly the same, not just close
+		assert_eq!(poly.evaluateAtNaive(x.clone()).0, answer);
+		assert_eq!(poly.evaluateAtNaiveImproved(x).0, answer);
+
+		//x^60 at 1/2 needs far more digits than fit in a machine word
+		let mut power = Polynomial::from(vec![GaussianRational::zero(); 61]);
+		power[60] = GaussianRational::one();
+
+		let (value, _) = power.evaluateAtHorner("1/2,0".parse().unwrap());
+		assert_eq!(value.re.to_string(), "1/1152921504606846976");
+
+	}
+
+}
@@ -0,0 +1,166 @@
+use std::fmt::Debug;
+use std::f64::consts::PI;
+use std::ops::{Add, Sub, Mul, Neg};
+
+use num_complex::{Complex32, Complex64};
+
+///This trait defines what a coefficient needs for the evaluators to run on it.
+///It is implemented for f32, f64, Complex32 and Complex64, and any other type
+///with the same arithmetic can implement it too. Coefficients are only ever cloned,
+///never copied, so types that keep their digits on the heap work as well.
+pub trait Scalar: Clone + Debug + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Neg<Output = Self> {
+
+	///The additive identity.
+	fn zero() -> Self;
+
+	///The multiplicative identity.
+	fn one() -> Self;
+
+	///Raises the value to a whole power. The default multiplies it out by repeated
+	///squaring, but types with a power function of their own can use it instead.
+	fn powi(self, exp: usize) -> Self {
+
+		let mut result = Self::one();
+		let mut base = self;
+		let mut exp = exp;
+
+		while exp > 0 {
+
+			if exp & 1 == 1 {
+
+				result = result * base.clone();
+
+			}
+
+			base = base.clone() * base;
+			exp >>= 1;
+
+		}
+
+		return result;
+
+	}
+
+}
+
+///This trait defines the scalars that have n-th roots of unity, which the FFT needs.
+pub trait RootOfUnity: Scalar {
+
+	///Calculates the root of unity e^(2 pi i k / n).
+	fn rootOfUnity(n: usize, k: usize) -> Self;
+
+}
+
+impl Scalar for f32 {
+
+	fn zero() -> f32 {
+
+		return 0.0;
+
+	}
+
+	fn one() -> f32 {
+
+		return 1.0;
+
+	}
+
+	fn powi(self, exp: usize) -> f32 {
+
+		return f32::powi(self, exp as i32);
+
+	}
+
+}
+
+impl Scalar for f64 {
+
+	fn zero() -> f64 {
+
+		return 0.0;
+
+	}
+
+	fn one() -> f64 {
+
+		return 1.0;
+
+	}
+
+	fn powi(self, exp: usize) -> f64 {
+
+		return f64::powi(self, exp as i32);
+
+	}
+
+}
+
+impl Scalar for Complex32 {
+
+	fn zero() -> Complex32 {
+
+		return Complex32::new(0.0, 0.0);
+
+	}
+
+	fn one() -> Complex32 {
+
+		return Complex32::new(1.0, 0.0);
+
+	}
+
+	fn powi(self, exp: usize) -> Complex32 {
+
+		return self.powf(exp as f32);
+
+	}
+
+}
+
+impl Scalar for Complex64 {
+
+	fn zero() -> Complex64 {
+
+		return Complex64::new(0.0, 0.0);
+
+	}
+
+	fn one() -> Complex64 {
+
+		return Complex64::new(1.0, 0.0);
+
+	}
+
+	fn powi(self, exp: usize) -> Complex64 {
+
+		return self.powf(exp as f64);
+
+	}
+
+}
+
+impl RootOfUnity for Complex32 {
+
+	fn rootOfUnity(n: usize, k: usize) -> Complex32 {
+
+		//worked out in double precision so the only rounding is the final one
+		let root = Complex64::rootOfUnity(n, k);
+
+		return Complex32::new(root.re as f32, root.im as f32);
+
+	}
+
+}
+
+impl RootOfUnity for Complex64 {
+
+	fn rootOfUnity(n: usize, k: usize) -> Complex64 {
+
+		let theta = (2.0 * PI * k as f64) / n as f64;
+		let (imag, real) = theta.sin_cos();
+
+		return Complex64::new(real, imag);
+
+	}
+
+}
@@ -0,0 +1,389 @@
+
+use std::str;
+use std::fs::File;
+use std::io::{Read, Write, BufWriter};
+
+use nom::IResult::*;
+use nom::Err::*;
+use num_complex::Complex64;
+
+use poly::{Polynomial, integer, fp, get_min_err};
+use scalar::Scalar;
+
+///Below this fraction of nonzero coefficients, the run time and multiplication count
+///comparisons also evaluate a dense polynomial's nonzero terms with SparsePolynomial.
+pub const SPARSE_DENSITY: f64 = 0.1;
+
+///A sparse polynomial only stores its nonzero terms, as (exponent, coefficient) pairs
+///sorted by exponent, so x^1000000 + 3x + 1 takes three pairs instead of a million
+///coefficients. No two terms share an exponent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparsePolynomial<T = Complex64> {
+
+	terms: Vec<(usize, T)>
+
+}
+
+//Create a function named "sparsefile" that returns the terms of a sparse polynomial.
+//Each term is zero or more newlines, followed by an integer exponent, a colon, and
+//zero or more spaces or tabs, followed by the coefficient the same way "polyfile"
+//has it, two floating point numbers separated by a comma.
+named!(sparsefile <Vec<(i64, Complex64)> >,
+    many0!(complete!(chain!(
+        many0!(tag!("\n")) ~
+        exp: integer ~
+        tag!(":") ~
+        many0!(alt!(tag!(" ") | tag!("\t"))) ~
+        re: fp ~
+        tag!(",") ~
+        many0!(alt!(tag!(" ") | tag!("\t"))) ~
+        im: fp ,
+        || (exp, Complex64::new(re, im))
+    )))
+);
+
+///Raises x to the exp power by repeated squaring. Returns the power and the
+///multiplication count, which is at most 2 log2(exp).
+fn powCount<T: Scalar>(x: T, exp: usize) -> (T, usize) {
+
+	let mut count: usize = 0;
+	let mut result: Option<T> = None;
+	let mut base = x;
+	let mut exp = exp;
+
+	//starting from nothing instead of one saves a multiply by one per power
+	while exp > 0 {
+
+		if exp & 1 == 1 {
+
+			result = match result {
+
+				Some(value) => { count += 1; Some(value * base.clone()) },
+				None 		=> Some(base.clone())
+
+			};
+
+		}
+
+		exp >>= 1;
+
+		if exp > 0 {
+
+			base = base.clone() * base;
+			count += 1;
+
+		}
+
+	}
+
+	return (result.unwrap_or_else(T::one), count);
+
+}
+
+///Tells whether few enough of the coefficients are nonzero that the sparse method
+///beats going through all of them.
+pub fn isSparse<T: Scalar>(poly: &Polynomial<T>) -> bool {
+
+	let nonzero = poly.iter().filter(|&coeff| *coeff != T::zero()).count();
+
+	return (nonzero as f64) < SPARSE_DENSITY * poly.len() as f64;
+
+}
+
+impl<T: Scalar> SparsePolynomial<T> {
+
+	///Builds a sparse polynomial from terms in any order, leaving out the zero ones.
+	///Returns an error message if two terms have the same exponent.
+	pub fn new(terms: Vec<(usize, T)>) -> Result<SparsePolynomial<T>, String> {
+
+		let mut terms = terms;
+		terms.sort_by_key(|&(exp, _)| exp);
+
+		for pair in terms.windows(2) {
+
+			if pair[0].0 == pair[1].0 {
+
+				return Err(format!("The exponent {} appears more than once.", pair[0].0));
+
+			}
+
+		}
+
+		terms.retain(|(_, coeff)| *coeff != T::zero());
+
+		return Ok(SparsePolynomial { terms });
+
+	}
+
+	///The number of nonzero terms.
+	pub fn termCount(&self) -> usize {
+
+		return self.terms.len();
+
+	}
+
+	///Expands the polynomial into every coefficient, zeros included, up to its degree.
+	pub fn toDense(&self) -> Polynomial<T> {
+
+		let len = self.terms.last().map_or(0, |&(exp, _)| exp + 1);
+		let mut coeffs = vec![T::zero(); len];
+
+		for (exp, coeff) in self.terms.iter() {
+
+			coeffs[*exp] = coeff.clone();
+
+		}
+
+		return Polynomial::from(coeffs);
+
+	}
+
+	///Evaluates the polynomial at x with Horner's method over the nonzero terms only,
+	///multiplying by x raised to the gap between each term and the next. The powers
+	///come from repeated squaring, so t terms of degree n take O(t log n) multiplies.
+	///Returns the value and the multiplication count.
+	pub fn evaluateAt(&self, x: T) -> (T, usize) {
+
+		let mut count: usize = 0;
+
+		if self.terms.is_empty() {
+
+			return (T::zero(), count);
+
+		}
+
+		let last = self.terms.len() - 1;
+		let mut sum = self.terms[last].1.clone();
+
+		for i in (0..last).rev() {
+
+			let (power, powerCount) = powCount(x.clone(), self.terms[i + 1].0 - self.terms[i].0);
+
+			sum = sum * power + self.terms[i].1.clone();
+			count += powerCount + 1;
+
+		}
+
+		//the lowest term may not be the constant one
+		if self.terms[0].0 > 0 {
+
+			let (power, powerCount) = powCount(x, self.terms[0].0);
+
+			sum = sum * power;
+			count += powerCount + 1;
+
+		}
+
+		return (sum, count);
+
+	}
+
+	///Evaluates at each of the points. Returns the values in the same order as the
+	///points and the multiplication count.
+	pub fn evaluateAtPoints(&self, points: &[T]) -> (Vec<T>, usize) {
+
+		let mut count: usize = 0;
+		let mut values = Vec::with_capacity(points.len());
+
+		for point in points {
+
+			let (value, pointCount) = self.evaluateAt(point.clone());
+			values.push(value);
+			count += pointCount;
+
+		}
+
+		return (values, count);
+
+	}
+
+}
+
+impl SparsePolynomial {
+
+	///Reads the polynomial from a file with one "exponent: re,im" term per line.
+	///Returns the read polynomial on success, or an error message on failure.
+	pub fn readFromFile(filename: &str) -> Result<SparsePolynomial, String> {
+
+		let file = File::open(filename.trim());
+		if file.is_err() { return Err("Failed to open file.".to_string()); }
+
+		let mut all_text = String::new();
+		let _ = file.unwrap().read_to_string(&mut all_text);
+
+		let terms = match sparsefile(all_text.as_bytes()) {
+
+			Done(extra, terms) => {
+
+				//there should be nothing left in the file, besides the newlines
+				//the parser stopped in front of
+				let extra_str = str::from_utf8(extra).unwrap().trim();
+
+				if extra_str.len() > 0 {
+
+					return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(extra_str.as_bytes())));
+
+				}
+
+				terms
+
+			},
+			Error(Position(_, pos)) => return Err(format!("Parsing failed. Invalid syntax '{}' in file.", get_min_err(pos))),
+			_ => return Err("Parsing failed, invalid format.".to_string())
+
+		};
+
+		return SparsePolynomial::new(terms.into_iter().map(|(exp, coeff)| (exp as usize, coeff)).collect());
+
+	}
+
+	///Writes the polynomial to a file in the format readFromFile reads.
+	pub fn writeToFile(&self, filename: &str) -> Result<(), String> {
+
+		let file = File::create(filename.trim());
+		if file.is_err() { return Err("Failed to create file.".to_string()); }
+
+		let mut outFile = BufWriter::new(file.unwrap());
+
+		//for each term, write "exponent: real,imag\n"
+		for &(exp, coeff) in self.terms.iter() {
+
+			let result = outFile.write(format!("{}: {},{}\n", exp, coeff.re, coeff.im).as_bytes());
+
+			if result.is_err() {
+
+				return Err("Could not write to file.".to_string());
+
+			}
+
+		}
+
+		return Ok(());
+
+	}
+
+	///Generates a pretty looking string to print to the console.
+	pub fn print(&self) -> String {
+
+		let terms: Vec<String> = self.terms.iter().map(|&(exp, coeff)| format!("({})(x^{})", coeff, exp)).collect();
+
+		return terms.join(" + ");
+
+	}
+
+}
+
+impl<T: Scalar> From<&Polynomial<T>> for SparsePolynomial<T> {
+
+	fn from(poly: &Polynomial<T>) -> SparsePolynomial<T> {
+
+		let terms = poly.iter().enumerate().filter(|&(_, coeff)| *coeff != T::zero()).map(|(exp, coeff)| (exp, coeff.clone())).collect();
+
+		return SparsePolynomial { terms };
+
+	}
+
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use poly::{Poly, Evaluate, rootsOfUnity};
+	use scalar::RootOfUnity;
+
+	#[test]
+	fn test_read_sparse_file(){
+
+		let result = SparsePolynomial::readFromFile("./data/sparse_valid.txt").unwrap();
+
+		assert_eq!(result.termCount(), 3);
+		assert_eq!(result.print(), "(1+0i)(x^0) + (3+0i)(x^1) + (1+0i)(x^1000000)");
+
+		let result = SparsePolynomial::readFromFile("./data/sparse_invalid_has_junk.txt");
+		assert_eq!(result, Err("Parsing failed. Invalid syntax \'x^2: 1,\' in file.".to_string()));
+
+		let result = SparsePolynomial::readFromFile("./data/sparse_invalid_repeated.txt");
+		assert_eq!(result, Err("The exponent 4 appears more than once.".to_string()));
+
+	}
+
+	#[test]
+	fn test_evaluate_sparse(){
+
+		let poly = SparsePolynomial::readFromFile("./data/sparse_valid.txt").unwrap();
+
+		//1000000 is 1 mod 7, so at a seventh root of unity this is 4w + 1
+		let root = Complex64::rootOfUnity(7, 1);
+		let (value, count) = poly.evaluateAt(root);
+
+		assert!((value - (4.0 * root + 1.0)).norm() < 1e-9);
+		assert!(count < 50, "{} multiplies is not logarithmic in the degree.", count);
+
+		//terms up to x^60, once with a constant term and once without
+		for &offset in [0, 3].iter() {
+
+			let dense = Polynomial::from((0..61).map(|i| if i % 5 == 0 { Complex64::new(i as f64, 1.0) } else { Complex64::new(0.0, 0.0) }).collect::<Vec<Complex64>>());
+			let mut shifted = vec![Complex64::new(0.0, 0.0); offset];
+			shifted.extend(dense.iter());
+
+			let dense = Polynomial::from(shifted);
+			let sparse = SparsePolynomial::from(&dense);
+			let x = Complex64::new(0.6, -0.7);
+
+			assert_eq!(sparse.toDense(), dense);
+			assert!((sparse.evaluateAt(x).0 - dense.evaluateAtHorner(x).0).norm() < 1e-9);
+
+		}
+
+		assert_eq!(SparsePolynomial::from(&Polynomial::new()).evaluateAt(root), (Complex64::new(0.0, 0.0), 0));
+
+	}
+
+	#[test]
+	fn test_sparse_against_dense(){
+
+		//x^1999 and two low terms, spread over 2000 coefficients
+		let mut coeffs = vec![Complex64::new(0.0, 0.0); 2000];
+		coeffs[0] = Complex64::new(1.0, 0.0);
+		coeffs[1] = Complex64::new(3.0, 0.0);
+		coeffs[1999] = Complex64::new(-2.0, 0.5);
+
+		let dense = Polynomial::from(coeffs);
+		let sparse = SparsePolynomial::from(&dense);
+
+		assert_eq!(sparse.termCount(), 3);
+		assert_eq!(sparse.toDense(), dense);
+		assert!(isSparse(&dense));
+		assert!(!isSparse(&Polynomial::from(vec![Complex64::new(1.0, 0.0); 10])));
+
+		for &x in [Complex64::new(0.5, 0.5), Complex64::new(-0.9, 0.1), Complex64::rootOfUnity(5, 2)].iter() {
+
+			let (value, count) = sparse.evaluateAt(x);
+			let (horner, hornerCount) = dense.evaluateAtHorner(x);
+
+			assert!((dense.evaluateAtNaive(x).0 - horner).norm() < 1e-9);
+			assert!((dense.evaluateAtNaiveImproved(x).0 - horner).norm() < 1e-9);
+			assert!((dense.evaluateAtEstrin(x).0 - horner).norm() < 1e-9);
+			assert!((value - horner).norm() < 1e-9);
+
+			//the dense evaluators go through every coefficient, zeros included
+			assert_eq!(hornerCount, 2000);
+			assert!(count < 50, "{} multiplies is not logarithmic in the degree.", count);
+
+		}
+
+		let (fftValues, fftCount) = dense.evaluateAtFFT();
+		let (values, count) = sparse.evaluateAtPoints(&rootsOfUnity(2000));
+
+		for (value, fftValue) in values.iter().zip(fftValues.iter()) {
+
+			assert!((value - fftValue).norm() < 1e-9);
+
+		}
+
+		assert!(count != fftCount);
+
+	}
+
+}